@@ -0,0 +1,77 @@
+//! Narrows the secret words down over six guesses the way the game does,
+//! filtering the list left by the guess before, and by filtering all of
+//! `WORDS` again after each guess. Prints how long each took. Run with
+//! `cargo bench --bench candidates`.
+
+use std::{hint::black_box, time::Instant};
+
+use yawc::{
+    filter::WordFilter,
+    game::{check_word, GuessEntry},
+    words::WORDS,
+};
+
+const ROUNDS: u32 = 20;
+
+fn main() {
+    let games = [
+        (
+            "crane",
+            ["slate", "pious", "dwelt", "boxer", "grace", "frank"],
+        ),
+        (
+            "geese",
+            ["eerie", "those", "sheep", "crane", "pious", "dwelt"],
+        ),
+        (
+            "going",
+            ["raise", "clout", "dying", "boxer", "fight", "plumb"],
+        ),
+    ];
+    let guesses: Vec<Vec<GuessEntry>> = games
+        .iter()
+        .map(|(secret, words)| {
+            words
+                .iter()
+                .map(|w| GuessEntry::new((*w).to_owned(), check_word(secret, w).to_vec()))
+                .collect()
+        })
+        .collect();
+
+    let refiltered = time(|| {
+        for guesses in &guesses {
+            for made in 1..=guesses.len() {
+                let filter = WordFilter::from_guesses(&guesses[..made]);
+                let candidates: Vec<_> = WORDS.iter().filter(|w| filter.matches(w)).collect();
+                black_box(candidates);
+            }
+        }
+    });
+    let cached = time(|| {
+        for guesses in &guesses {
+            let mut candidates = WORDS.to_vec();
+            for made in 1..=guesses.len() {
+                let filter = WordFilter::from_guesses(&guesses[..made]);
+                candidates.retain(|w| filter.matches(w));
+                black_box(&candidates);
+            }
+        }
+    });
+
+    println!("all of WORDS:  {refiltered:>8.1?} per round");
+    println!("cached list:   {cached:>8.1?} per round");
+    println!(
+        "speedup:       {:>8.1}×",
+        refiltered.as_secs_f64() / cached.as_secs_f64()
+    );
+}
+
+/// Average time `f` takes over [`ROUNDS`] rounds, after one to warm up.
+fn time(mut f: impl FnMut()) -> std::time::Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
@@ -0,0 +1,68 @@
+//! Filters the secret words with [`WordFilter`] and with the naive loop it
+//! replaced, checking every word against every guess, and prints how long
+//! each took. Run with `cargo bench --bench word_filter`.
+
+use std::{hint::black_box, time::Instant};
+
+use yawc::{
+    filter::WordFilter,
+    game::{check_word, GuessEntry},
+    words::WORDS,
+};
+
+const ROUNDS: u32 = 20;
+
+fn main() {
+    let games = [
+        ("crane", &["slate", "pious"][..]),
+        ("geese", &["eerie", "those", "sheep"][..]),
+        ("plant", &["llama", "stamp"][..]),
+        ("going", &["raise", "clout", "dying"][..]),
+    ];
+    let guesses: Vec<Vec<GuessEntry>> = games
+        .iter()
+        .map(|(secret, words)| {
+            words
+                .iter()
+                .map(|w| GuessEntry::new((*w).to_owned(), check_word(secret, w).to_vec()))
+                .collect()
+        })
+        .collect();
+
+    let naive = time(|| {
+        for guesses in &guesses {
+            let left = WORDS
+                .iter()
+                .filter(|word| {
+                    guesses
+                        .iter()
+                        .all(|g| check_word(word, &g.word)[..] == g.matches[..])
+                })
+                .count();
+            black_box(left);
+        }
+    });
+    let filter = time(|| {
+        for guesses in &guesses {
+            let filter = WordFilter::from_guesses(guesses);
+            black_box(WORDS.iter().filter(|word| filter.matches(word)).count());
+        }
+    });
+
+    println!("naive loop:  {naive:>8.1?} per round");
+    println!("WordFilter:  {filter:>8.1?} per round");
+    println!(
+        "speedup:     {:>8.1}×",
+        naive.as_secs_f64() / filter.as_secs_f64()
+    );
+}
+
+/// Average time `f` takes over [`ROUNDS`] rounds, after one to warm up.
+fn time(mut f: impl FnMut()) -> std::time::Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
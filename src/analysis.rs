@@ -0,0 +1,272 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use crate::{
+    solver::word_entropy_ranking,
+    words::{ACCEPTABLE, WORDS, WORDS_PLUS},
+};
+
+/// Number of occurrences of each letter `a..=z` across all words.
+#[must_use]
+pub fn letter_frequencies(words: &[&str]) -> [usize; 26] {
+    let mut freqs = [0; 26];
+    for b in words.iter().flat_map(|w| w.bytes()) {
+        freqs[usize::from(b - b'a')] += 1;
+    }
+    freqs
+}
+
+/// The 3 letters most often found at each of the 5 positions of
+/// `candidates`, most common first, along with the fraction of candidates
+/// having them there. Positions with fewer different letters are padded with
+/// `(' ', 0.0)`.
+///
+/// ```
+/// use yawc::analysis::positional_frequencies;
+///
+/// let freqs = positional_frequencies(&["crane", "crate", "grace", "trace"]);
+/// assert_eq!(freqs[0][0], ('c', 0.5));
+/// assert_eq!(freqs[1][0], ('r', 1.0));
+/// assert_eq!(freqs[1][1], (' ', 0.0));
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn positional_frequencies(candidates: &[&str]) -> [[(char, f32); 3]; 5] {
+    let mut counts: [HashMap<char, usize>; 5] = Default::default();
+    for word in candidates {
+        for (counts, c) in counts.iter_mut().zip(word.chars()) {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+    }
+    let total = candidates.len().max(1) as f32;
+    counts.map(|counts| {
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let mut top = [(' ', 0.0); 3];
+        for (top, (c, n)) in top.iter_mut().zip(counts) {
+            *top = (c, n as f32 / total);
+        }
+        top
+    })
+}
+
+/// Fraction of `candidates` having the same letter at both positions `i` and
+/// `j`, for each pair of the 5 positions. The diagonal is all `1.0`, unless
+/// there are no candidates at all.
+///
+/// ```
+/// use yawc::analysis::position_correlation_matrix;
+///
+/// let matrix = position_correlation_matrix(&["geese", "eerie", "crane", "fluff"]);
+/// assert_eq!(matrix[0][0], 1.0);
+/// // the E's of GEESE and EERIE
+/// assert_eq!(matrix[1][4], 0.5);
+/// assert_eq!(matrix[4][1], 0.5);
+/// // the F's of FLUFF
+/// assert_eq!(matrix[0][3], 0.25);
+/// assert_eq!(matrix[0][1], 0.25);
+/// assert_eq!(position_correlation_matrix(&[])[0][0], 0.0);
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn position_correlation_matrix(candidates: &[&str]) -> [[f32; 5]; 5] {
+    let mut counts = [[0usize; 5]; 5];
+    for word in candidates {
+        let letters: Vec<_> = word.chars().collect();
+        for (i, row) in counts.iter_mut().enumerate() {
+            for (j, count) in row.iter_mut().enumerate() {
+                if letters.get(i).is_some_and(|c| letters.get(j) == Some(c)) {
+                    *count += 1;
+                }
+            }
+        }
+    }
+    let total = candidates.len().max(1) as f32;
+    counts.map(|row| row.map(|n| n as f32 / total))
+}
+
+/// Rough guess of how common `word` is in everyday English, from `0` for rare
+/// words to `4` for common ones.
+///
+/// There's no word frequency data to go by, so this goes by whether `word` is
+/// one of the secret words, which were picked to be familiar, and by how
+/// common its letters are.
+#[must_use]
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+pub fn word_frequency_tier(word: &str) -> u8 {
+    let freqs = letter_frequencies(WORDS);
+    let max = freqs.iter().copied().max().unwrap_or(1) as f64;
+    let letters: Vec<_> = word
+        .bytes()
+        .filter(u8::is_ascii_lowercase)
+        .map(|b| freqs[usize::from(b - b'a')] as f64 / max)
+        .collect();
+    let commonness = letters.iter().sum::<f64>() / letters.len().max(1) as f64;
+    let listed = WORDS.contains(&word) || WORDS_PLUS.contains(&word);
+    u8::from(listed) * 2 + ((commonness * 3.0) as u8).min(2)
+}
+
+/// Number of letters to insert, remove or replace to turn `a` into `b`.
+///
+/// ```
+/// use yawc::analysis::levenshtein;
+///
+/// assert_eq!(levenshtein("crane", "crane"), 0);
+/// assert_eq!(levenshtein("crane", "crate"), 1);
+/// assert_eq!(levenshtein("crane", "rants"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// ```
+#[must_use]
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut row: Vec<_> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let replaced = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = replaced.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The word of `words` closest to `word`, other than `word` itself, along
+/// with its [`levenshtein`] distance from it.
+#[must_use]
+pub fn nearest_word<'w>(word: &str, words: &[&'w str]) -> Option<(&'w str, usize)> {
+    words
+        .iter()
+        .filter(|&&w| w != word)
+        .map(|&w| (w, levenshtein(word, w)))
+        .min_by_key(|&(_, distance)| distance)
+}
+
+/// Percentage of secret words whose letters are, summed up, less common than
+/// those of `word`, going by [`letter_frequencies`] of [`WORDS`].
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn letter_frequency_percentile(word: &str) -> f64 {
+    let freqs = letter_frequencies(WORDS);
+    let score = |w: &str| -> usize {
+        w.bytes()
+            .filter(u8::is_ascii_lowercase)
+            .map(|b| freqs[usize::from(b - b'a')])
+            .sum()
+    };
+    let word_score = score(word);
+    let below = WORDS.iter().filter(|w| score(w) < word_score).count();
+    below as f64 / WORDS.len() as f64 * 100.0
+}
+
+/// The `n` most common two-letter word beginnings, most common first.
+#[must_use]
+pub fn starting_bigrams<'w>(words: &[&'w str], n: usize) -> Vec<(&'w str, usize)> {
+    let mut counts = HashMap::new();
+    for word in words {
+        *counts.entry(&word[..2]).or_insert(0) += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    counts.truncate(n);
+    counts
+}
+
+fn unique_letters(word: &str) -> usize {
+    let mut seen = 0u32;
+    for b in word.bytes() {
+        seen |= 1 << (b - b'a');
+    }
+    seen.count_ones() as usize
+}
+
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn average_unique_letters(words: &[&str]) -> f64 {
+    let total: usize = words.iter().map(|w| unique_letters(w)).sum();
+    total as f64 / words.len() as f64
+}
+
+/// Number of words by how many of their letters are repeats, i.e. index `0`
+/// holds words with five distinct letters, index `1` words with four, etc.
+#[must_use]
+pub fn repeated_letters_distribution(words: &[&str]) -> [usize; 5] {
+    let mut dist = [0; 5];
+    for word in words {
+        dist[word.len() - unique_letters(word)] += 1;
+    }
+    dist
+}
+
+/// The `n` words of [`WORDS`] with the highest entropy against the whole
+/// list, best first, from [`word_entropy_ranking`].
+#[must_use]
+pub fn best_openers(n: usize) -> &'static [(&'static str, f64)] {
+    let ranking = word_entropy_ranking();
+    &ranking[..n.min(ranking.len())]
+}
+
+#[allow(clippy::missing_errors_doc, clippy::cast_precision_loss)]
+pub fn print_report(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "┌────────────┬───────┐")?;
+    writeln!(out, "│ List       │ Words │")?;
+    writeln!(out, "├────────────┼───────┤")?;
+    writeln!(out, "│ secret     │ {:>5} │", WORDS.len())?;
+    writeln!(out, "│ acceptable │ {:>5} │", ACCEPTABLE.len())?;
+    writeln!(
+        out,
+        "│ total      │ {:>5} │",
+        WORDS.len() + ACCEPTABLE.len()
+    )?;
+    writeln!(out, "└────────────┴───────┘")?;
+
+    let freqs = letter_frequencies(WORDS);
+    let max = freqs.iter().copied().max().unwrap_or(1);
+    let mut letters: Vec<_> = (b'a'..=b'z').map(char::from).zip(freqs).collect();
+    letters.sort_by_key(|&(_, n)| std::cmp::Reverse(n));
+    writeln!(out, "\nLetter frequency")?;
+    writeln!(
+        out,
+        "┌───┬───────┬────────────────────────────────────────┐"
+    )?;
+    for (c, n) in letters {
+        writeln!(out, "│ {c} │ {n:>5} │ {:<40} │", "█".repeat(n * 40 / max))?;
+    }
+    writeln!(
+        out,
+        "└───┴───────┴────────────────────────────────────────┘"
+    )?;
+
+    writeln!(out, "\nMost common starting bigrams")?;
+    writeln!(out, "┌────┬───────┐")?;
+    for (bigram, n) in starting_bigrams(WORDS, 10) {
+        writeln!(out, "│ {bigram} │ {n:>5} │")?;
+    }
+    writeln!(out, "└────┴───────┘")?;
+
+    writeln!(
+        out,
+        "\nAverage unique letters per word: {:.3}",
+        average_unique_letters(WORDS)
+    )?;
+
+    writeln!(out, "\nRepeated letters")?;
+    writeln!(out, "┌─────────┬───────┬────────┐")?;
+    for (repeats, n) in repeated_letters_distribution(WORDS).iter().enumerate() {
+        let percent = *n as f64 / WORDS.len() as f64 * 100.0;
+        writeln!(out, "│ {repeats:>7} │ {n:>5} │ {percent:>5.1}% │")?;
+    }
+    writeln!(out, "└─────────┴───────┴────────┘")?;
+
+    writeln!(out, "\nBest openers")?;
+    writeln!(out, "┌───────┬───────────┐")?;
+    for &(word, bits) in best_openers(5) {
+        writeln!(out, "│ {word} │ {bits:>4.2} bits │")?;
+    }
+    writeln!(out, "└───────┴───────────┘")?;
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use std::{thread, time::Duration};
+
+use crossterm::{
+    cursor, execute,
+    style::{self, Color, Stylize},
+    terminal,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::raw::Terminal;
+
+const CONFETTI_COLORS: [Color; 6] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+];
+
+/// Scatters 20 stars of random colours over the screen, flashes them three
+/// times and clears the screen. The same seed is used every time, so the
+/// stars always land in the same spots.
+#[allow(clippy::missing_errors_doc)]
+pub fn confetti_animation(term: &mut Terminal, width: u16, height: u16) -> std::io::Result<()> {
+    let mut rng = StdRng::seed_from_u64(0x0059_4157_4300);
+    let stars: Vec<_> = (0..20)
+        .map(|_| {
+            let color = CONFETTI_COLORS[rng.gen_range(0..CONFETTI_COLORS.len())];
+            (rng.gen_range(0..width), rng.gen_range(0..height), color)
+        })
+        .collect();
+
+    for _ in 0..3 {
+        for &(x, y, color) in &stars {
+            execute!(
+                term,
+                cursor::MoveTo(x, y),
+                style::PrintStyledContent('*'.with(color).bold())
+            )?;
+        }
+        thread::sleep(Duration::from_millis(200));
+        for &(x, y, _) in &stars {
+            execute!(term, cursor::MoveTo(x, y), style::Print(' '))?;
+        }
+        thread::sleep(Duration::from_millis(200));
+    }
+
+    execute!(term, terminal::Clear(terminal::ClearType::All))
+}
+
+/// Guess that plays [`easter_egg_animation`] instead of being checked: the
+/// name of the game, spelt wrong.
+pub const EASTER_EGG_WORD: &str = "yawcw";
+
+/// Big letters of `YAWC`, five rows each.
+const BIG_LETTERS: [[&str; 5]; 4] = [
+    ["█   █", " █ █ ", "  █  ", "  █  ", "  █  "],
+    [" ███ ", "█   █", "█████", "█   █", "█   █"],
+    ["█   █", "█   █", "█ █ █", "██ ██", "█   █"],
+    [" ████", "█    ", "█    ", "█    ", " ████"],
+];
+
+/// Drops `YAWC` in big letters from the top of the screen one at a time,
+/// each in its own colour and bouncing as it lands, then clears the screen.
+/// Does nothing if the screen is too small for the letters.
+#[allow(clippy::missing_errors_doc)]
+pub fn easter_egg_animation(term: &mut Terminal, width: u16, height: u16) -> std::io::Result<()> {
+    const LETTER_WIDTH: u16 = 7;
+    let rows = BIG_LETTERS[0].len() as u16;
+    let total_width = LETTER_WIDTH * BIG_LETTERS.len() as u16;
+    // the bounce needs two rows above the floor
+    if width < total_width || height < rows + 5 {
+        return Ok(());
+    }
+    let left = (width - total_width) / 2;
+    let floor = (height - rows) / 2;
+
+    execute!(term, terminal::Clear(terminal::ClearType::All))?;
+    for ((i, letter), color) in (0u16..).zip(BIG_LETTERS).zip(CONFETTI_COLORS) {
+        let x = left + i * LETTER_WIDTH;
+        // falls to the floor, then bounces up two rows and back down
+        let path = (0..=floor).chain([floor - 1, floor - 2, floor - 1, floor]);
+        let mut previous = None;
+        for y in path {
+            if let Some(previous) = previous {
+                for row in 0..rows {
+                    execute!(
+                        term,
+                        cursor::MoveTo(x, previous + row),
+                        style::Print("     ")
+                    )?;
+                }
+            }
+            for (row, line) in (0..).zip(letter) {
+                execute!(
+                    term,
+                    cursor::MoveTo(x, y + row),
+                    style::PrintStyledContent(line.with(color).bold())
+                )?;
+            }
+            previous = Some(y);
+            thread::sleep(Duration::from_millis(30));
+        }
+    }
+    thread::sleep(Duration::from_millis(800));
+
+    execute!(term, terminal::Clear(terminal::ClearType::All))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::TerminalSize;
+
+    #[test]
+    fn easter_egg_skips_screens_too_short_to_bounce_on() {
+        for height in 0..BIG_LETTERS[0].len() as u16 + 5 {
+            let mut term = Terminal::headless(TerminalSize { width: 80, height });
+            easter_egg_animation(&mut term, 80, height).unwrap();
+        }
+    }
+
+    #[test]
+    fn easter_egg_on_the_shortest_screen() {
+        let mut term = Terminal::headless(TerminalSize {
+            width: 80,
+            height: 10,
+        });
+        easter_egg_animation(&mut term, 80, 10).unwrap();
+    }
+}
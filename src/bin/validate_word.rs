@@ -0,0 +1,44 @@
+//! Checks a word proposed for the word lists, without running the game.
+
+use std::process::ExitCode;
+
+use clap::Parser;
+use yawc::{
+    analysis::{letter_frequency_percentile, nearest_word},
+    words::{ACCEPTABLE, WORDS},
+};
+
+/// Report on how a word fits in the word lists
+#[derive(Parser, Debug)]
+#[command(name = "validate-word", version)]
+struct Args {
+    /// The word to check
+    word: String,
+}
+
+fn main() -> ExitCode {
+    let word = Args::parse().word.to_lowercase();
+    if word.len() != 5 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        eprintln!("Error: {word:?} is not a word of 5 letters a-z");
+        return ExitCode::FAILURE;
+    }
+
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    println!("word:                 {word}");
+    println!(
+        "in secret words:      {}",
+        yes_no(WORDS.contains(&word.as_str()))
+    );
+    println!(
+        "in acceptable words:  {}",
+        yes_no(ACCEPTABLE.contains(&word.as_str()))
+    );
+    if let Some((nearest, distance)) = nearest_word(&word, WORDS) {
+        println!("nearest secret word:  {nearest} (distance {distance})");
+    }
+    println!(
+        "letter frequency:     {:.1} percentile",
+        letter_frequency_percentile(&word)
+    );
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,148 @@
+use std::{
+    borrow::Cow,
+    env, fmt,
+    io::{self, IsTerminal, Write},
+    sync::OnceLock,
+};
+
+use crossterm::{cursor, execute, terminal};
+
+use crate::theme::{detect_color_support, ColorSupport};
+
+/// What the terminal can do, found out once at startup so that drawing falls
+/// back to something simpler where it can't. Only the colours and unicode
+/// are really probed; terminals can't be asked about the rest, so it goes by
+/// `$TERM`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerminalCapability {
+    pub color: ColorSupport,
+    /// Whether box drawing characters show up as one column each, rather
+    /// than as garbage on terminals that don't speak UTF-8.
+    pub unicode: bool,
+    /// Whether mouse reporting (`DECSET 1000`) can be turned on.
+    pub mouse: bool,
+    pub alternate_screen: bool,
+    /// Whether the cursor can be hidden and shown again.
+    pub cursor_visibility: bool,
+}
+
+impl TerminalCapability {
+    /// Probes the terminal, or only looks at the environment if stdout isn't
+    /// one. Done once, later calls give the same result.
+    #[must_use]
+    pub fn detect() -> Self {
+        static CAPABILITY: OnceLock<TerminalCapability> = OnceLock::new();
+        *CAPABILITY.get_or_init(|| {
+            let mut capability = Self::from_env(
+                &env::var("TERM").unwrap_or_default(),
+                &locale(),
+                detect_color_support(),
+            );
+            if io::stdout().is_terminal() {
+                if let Some(unicode) = probe_unicode() {
+                    capability.unicode = unicode;
+                }
+            }
+            capability
+        })
+    }
+
+    /// What a terminal called `term`, with the `locale` and `color` support
+    /// given, is known to do.
+    ///
+    /// ```
+    /// use yawc::{capability::TerminalCapability, theme::ColorSupport};
+    ///
+    /// let xterm = TerminalCapability::from_env("xterm-256color", "en_US.UTF-8", ColorSupport::Ansi256);
+    /// assert!(xterm.unicode && xterm.mouse && xterm.alternate_screen && xterm.cursor_visibility);
+    ///
+    /// let console = TerminalCapability::from_env("linux", "C", ColorSupport::Basic);
+    /// assert!(!console.unicode && !console.mouse && !console.alternate_screen);
+    /// assert!(console.cursor_visibility);
+    ///
+    /// let dumb = TerminalCapability::from_env("dumb", "en_US.utf8", ColorSupport::None);
+    /// assert!(dumb.unicode && !dumb.cursor_visibility);
+    /// ```
+    #[must_use]
+    pub fn from_env(term: &str, locale: &str, color: ColorSupport) -> Self {
+        let locale = locale.to_ascii_lowercase();
+        let plain = matches!(term, "" | "dumb");
+        Self {
+            color,
+            unicode: locale.contains("utf-8") || locale.contains("utf8"),
+            mouse: !plain && term != "linux",
+            alternate_screen: !plain && !matches!(term, "linux" | "vt100" | "vt220"),
+            cursor_visibility: !plain,
+        }
+    }
+}
+
+/// Shown with `--verbose`.
+impl fmt::Display for TerminalCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let yes_no = |b| if b { "yes" } else { "no, falling back" };
+        writeln!(f, "colours:           {:?}", self.color)?;
+        writeln!(f, "unicode:           {}", yes_no(self.unicode))?;
+        writeln!(f, "mouse:             {}", yes_no(self.mouse))?;
+        writeln!(f, "alternate screen:  {}", yes_no(self.alternate_screen))?;
+        write!(f, "cursor visibility: {}", yes_no(self.cursor_visibility))
+    }
+}
+
+/// The locale characters are encoded in, as the C library picks it.
+fn locale() -> String {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default()
+}
+
+/// Prints a box drawing character at the start of the line and asks the
+/// terminal where the cursor went, then rubs it out. `None` if the terminal
+/// doesn't answer.
+fn probe_unicode() -> Option<bool> {
+    let mut stdout = io::stdout();
+    print!("\r─");
+    stdout.flush().ok()?;
+    let column = cursor::position().ok().map(|(column, _)| column);
+    print!("\r");
+    execute!(stdout, terminal::Clear(terminal::ClearType::UntilNewLine)).ok()?;
+    column.map(|column| column == 1)
+}
+
+/// `text` with the box drawing and other symbols the game draws with turned
+/// into ASCII, for terminals without unicode.
+///
+/// ```
+/// use yawc::capability::ascii_fallback;
+///
+/// assert_eq!(ascii_fallback("┌───┬───┐"), "+---+---+");
+/// assert_eq!(ascii_fallback("│ A │ B₁₂│"), "| A | B12|");
+/// assert_eq!(ascii_fallback("plain"), "plain");
+/// ```
+#[must_use]
+pub fn ascii_fallback(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.chars()
+            .map(|c| match c {
+                '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' => '+',
+                '─' => '-',
+                '│' => '|',
+                '█' => '#',
+                '░' => '.',
+                '▶' => '>',
+                '▲' => '^',
+                '▼' => 'v',
+                '★' => '*',
+                '☆' => '.',
+                '×' => 'x',
+                '₀'..='₉' => char::from_digit(u32::from(c) - u32::from('₀'), 10).unwrap_or(c),
+                c => c,
+            })
+            .collect(),
+    )
+}
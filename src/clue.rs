@@ -0,0 +1,92 @@
+use std::{fmt, str::FromStr};
+
+use crate::game::Match;
+
+/// Something known about the secret word before the first guess, like from
+/// a game started elsewhere.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clue {
+    pub letter: char,
+    pub position: usize,
+    /// What guessing `letter` at `position` showed.
+    pub kind: Match,
+}
+
+/// Clues given with `--clue`, as patterns of the length of the word with `?`
+/// where nothing is known, each followed by what the letters in it are: `1`
+/// for correct, `2` for misplaced and `0` for not in the word.
+///
+/// ```
+/// use yawc::{clue::{Clue, Clues}, game::Match};
+///
+/// let clues: Clues = "C????:1 A????:2".parse().unwrap();
+/// assert_eq!(
+///     clues.clues,
+///     [
+///         Clue { letter: 'c', position: 0, kind: Match::Correct },
+///         Clue { letter: 'a', position: 0, kind: Match::Misplaced },
+///     ]
+/// );
+/// assert_eq!(clues.len, 5);
+/// assert!("C????".parse::<Clues>().is_err());
+/// assert!("C????:1 A???:2".parse::<Clues>().is_err());
+/// assert!("C????:4".parse::<Clues>().is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Clues {
+    pub clues: Vec<Clue>,
+    /// Length of the patterns, which is that of the word.
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCluesError(String);
+
+impl fmt::Display for ParseCluesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid clue {:?}, expected a pattern like C???? followed by :1 (correct), :2 (misplaced) or :0 (not in the word)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseCluesError {}
+
+impl FromStr for Clues {
+    type Err = ParseCluesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut clues = Vec::new();
+        let mut len = 0;
+        for token in s.split_whitespace() {
+            let error = || ParseCluesError(token.to_owned());
+            let (pattern, kind) = token.rsplit_once(':').ok_or_else(error)?;
+            let kind = match kind {
+                "1" => Match::Correct,
+                "2" => Match::Misplaced,
+                "0" => Match::Incorrect,
+                _ => return Err(error()),
+            };
+            let start = clues.len();
+            for (position, c) in pattern.chars().enumerate() {
+                match c {
+                    '?' => {}
+                    c if c.is_alphabetic() => clues.push(Clue {
+                        letter: c.to_lowercase().next().unwrap_or(c),
+                        position,
+                        kind,
+                    }),
+                    _ => return Err(error()),
+                }
+            }
+            let pattern_len = pattern.chars().count();
+            if clues.len() == start || (len != 0 && pattern_len != len) {
+                return Err(error());
+            }
+            len = pattern_len;
+        }
+        Ok(Self { clues, len })
+    }
+}
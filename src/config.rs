@@ -0,0 +1,348 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::{Parser, Subcommand};
+
+use crate::{
+    clue::Clues,
+    crossword::CrosswordChecker,
+    generator,
+    keyboard::KeyboardPosition,
+    locale::{bundled_word_lists, Locale},
+    mode::Mode,
+    puzzle::PuzzleSet,
+    stats::OutputFormat,
+    theme::{EmojiStyle, Theme},
+    variant::WordleVariant,
+};
+
+#[derive(Debug, Default, Parser)]
+#[command(version, about)]
+pub struct Config {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Read guesses from stdin and print the results instead of starting the game
+    #[arg(long)]
+    pub batch: bool,
+
+    /// Secret word to use in batch mode instead of a random one
+    #[arg(long, value_name = "WORD", requires = "batch")]
+    pub word: Option<String>,
+
+    /// Run the solver against every secret word and report how it did
+    #[arg(long)]
+    pub profile_solver: bool,
+
+    /// Print the JSON Schema of game records and exit
+    #[arg(long)]
+    pub json_schema: bool,
+
+    /// Redraw the screen N times without showing it and report how long it took
+    #[arg(long, value_name = "N")]
+    pub benchmark_fps: Option<u32>,
+
+    /// Play a tournament of 10 games scored by the total number of guesses
+    #[arg(long)]
+    pub tournament: bool,
+
+    /// Play the same word in turns with 3 other players, ranked by guesses
+    #[arg(long = "tournament-4")]
+    pub tournament_4: bool,
+
+    /// Seed of the secret words, random if not given
+    #[arg(long, value_name = "SEED")]
+    pub seed: Option<u64>,
+
+    /// Play the word of the day, the same for everyone on a given day
+    #[arg(long, conflicts_with_all = ["seed", "tournament"])]
+    pub daily: bool,
+
+    /// Play the puzzles in this JSON file instead of random words
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["daily", "tournament"])]
+    pub puzzle_set: Option<PathBuf>,
+
+    /// What's known about the word before the first guess, like "C????:1 A????:2":
+    /// patterns of the word with letters that are correct (1), misplaced (2) or
+    /// not in the word (0)
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        conflicts_with_all = ["daily", "tournament", "tournament_4", "puzzle_set"]
+    )]
+    pub clue: Option<Clues>,
+
+    /// Play the words of the session as a crossword, like "1:3=2:4": letter 3
+    /// of the first word is letter 4 of the second, so once it's found there
+    /// the guesses for the second word must have it
+    #[arg(long, value_name = "CROSSINGS")]
+    pub crossword: Option<CrosswordChecker>,
+
+    /// Start each secret word with the last letter of the one before
+    #[arg(long, conflicts_with_all = ["daily", "tournament", "tournament_4", "puzzle_set"])]
+    pub wordchain: bool,
+
+    /// Puzzles loaded from --puzzle-set
+    #[arg(skip)]
+    pub puzzles: Option<PuzzleSet>,
+
+    /// Variant of the game to play
+    #[arg(long, value_enum, default_value_t)]
+    pub mode: Mode,
+
+    /// Which Wordle to play, instead of picking it from the menu yawc starts
+    /// with
+    #[arg(long, value_enum)]
+    pub variant: Option<WordleVariant>,
+
+    /// Language of the words and the keyboard layout
+    #[arg(long, value_enum, value_name = "LANG", default_value_t)]
+    pub locale: Locale,
+
+    /// Play with the newline-separated word list at this URL, downloaded at
+    /// most once a day
+    #[cfg(feature = "download")]
+    #[arg(long, value_name = "URL")]
+    pub wordlist_url: Option<String>,
+
+    /// Play with the newline-separated word list in this file, used for both
+    /// the secret and the guessed words
+    #[arg(long, value_name = "FILE")]
+    pub word_list: Option<PathBuf>,
+
+    /// Words from --word-list or --wordlist-url, used for both the secret and
+    /// the guessed words when set
+    #[arg(skip)]
+    pub custom_words: Option<&'static [&'static str]>,
+
+    /// Opening guess submitted by itself at the start of every game
+    #[arg(long, value_name = "WORD")]
+    pub first_guess: Option<String>,
+
+    /// Time limit for each guess in milliseconds, after which it's submitted
+    /// as it is
+    #[arg(long = "timeout-per-guess", value_name = "MS", value_parser = parse_millis)]
+    pub per_guess_timer: Option<Duration>,
+
+    /// Time limit for the whole session in seconds, after which the game is
+    /// saved, to be picked up with `yawc resume`, and yawc quits
+    #[arg(long, value_name = "SECONDS", value_parser = parse_secs)]
+    pub max_time_per_session: Option<Duration>,
+
+    /// Letters revealed by earlier guesses must be used again: those found in
+    /// place where they were, and misplaced ones anywhere
+    #[arg(long, hide = true)]
+    pub hard: bool,
+
+    /// Letters ruled out by earlier guesses can't be used again
+    #[arg(long, hide = true)]
+    pub superhard: bool,
+
+    /// Print what the terminal was found to support before starting
+    #[arg(long)]
+    pub verbose: bool,
+
+    /// Rank every word as a first guess before starting, showing how far
+    /// along it is, rather than in the background
+    #[arg(long)]
+    pub precompute: bool,
+
+    /// Append a JSON line to this file for every key press, guess and reveal
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Save each finished game to this file as an SVG image of its tiles
+    #[cfg(feature = "svg")]
+    #[arg(long, value_name = "PATH")]
+    pub export_svg: Option<PathBuf>,
+
+    /// Play back the guesses of a --log-file log; hold F to fast-forward, S
+    /// to slow down, Space pauses
+    #[arg(long, value_name = "PATH")]
+    pub replay: Option<PathBuf>,
+
+    /// Rate how common each guessed word is with one to five stars
+    #[arg(long)]
+    pub frequency_hints: bool,
+
+    /// After each guess, show which letters it shares with the one before
+    #[arg(long)]
+    pub word_relationship: bool,
+
+    /// Show which earlier guesses share letters with the last one
+    #[arg(long)]
+    pub word_graph: bool,
+
+    /// Draw keyboard keys twice as wide
+    #[arg(long)]
+    pub wide_keys: bool,
+
+    /// Show the keyboard flipped left to right, as a challenge
+    #[arg(long)]
+    pub mirror: bool,
+
+    /// Dim the guesses made so far, leaving the current one and the keyboard
+    /// bright; Ctrl+O switches it on and off
+    #[arg(long)]
+    pub focus: bool,
+
+    /// Hide the on-screen keyboard
+    #[arg(long)]
+    pub no_keyboard: bool,
+
+    /// Mark keyboard keys with the positions their letter is known not to
+    /// be at
+    #[arg(long)]
+    pub smart_keyboard: bool,
+
+    /// Draw the keyboard above or below the grid
+    #[arg(long, value_enum, default_value_t)]
+    pub keyboard_position: KeyboardPosition,
+
+    /// Don't suggest the best next guess under the keyboard
+    #[arg(long)]
+    pub no_suggestions: bool,
+
+    /// Don't play sound effects when a game ends
+    #[arg(long)]
+    pub no_sound: bool,
+
+    /// Let Ctrl+D show the internal state of the game, as in debug builds
+    #[arg(long)]
+    pub debug: bool,
+
+    /// Don't show a tip when the game starts
+    #[arg(long)]
+    pub no_tips: bool,
+
+    /// Don't show any messages under the keyboard, apart from questions that
+    /// need an answer and warnings; Ctrl+Q switches them on and off
+    #[arg(long)]
+    pub quiet: bool,
+
+    /// Don't play the celebration animation after a first-guess win
+    #[arg(long)]
+    pub no_animation: bool,
+
+    /// Puzzle number to show in the title, like "Wordle #1234"; today's
+    /// with --daily
+    #[arg(long, value_name = "N")]
+    pub number: Option<u32>,
+
+    /// Colour theme, see `yawc themes` for the available ones
+    #[arg(long, default_value_t)]
+    pub theme: Theme,
+
+    /// Use different colours for correct and misplaced letters in every game
+    #[arg(long)]
+    pub rainbow: bool,
+
+    /// Show the results of guesses as coloured squares instead of letters
+    #[arg(long)]
+    pub emoji: bool,
+
+    /// Which set of squares to use with --emoji
+    #[arg(long, value_enum, default_value_t, requires = "emoji")]
+    pub emoji_style: EmojiStyle,
+
+    /// After each game, show a QR code of its squares, to share from a
+    /// phone; q shows it too
+    #[cfg(feature = "qr")]
+    #[arg(long)]
+    pub share_qr: bool,
+}
+
+impl Config {
+    /// Number of the puzzle, shown like "Wordle #1234": `--number`, or
+    /// today's with `--daily`.
+    #[must_use]
+    pub fn puzzle_number(&self) -> Option<u32> {
+        self.number
+            .or_else(|| self.daily.then(generator::todays_number))
+    }
+
+    /// Words the secret word is picked from.
+    #[must_use]
+    pub fn secret_words(&self) -> &'static [&'static str] {
+        if let Some(words) = self.custom_words {
+            return words;
+        }
+        match self.locale {
+            Locale::En => self.mode.secret_words(),
+            locale => bundled_word_lists(locale.code()).0,
+        }
+    }
+
+    /// All the words that can be guessed.
+    #[must_use]
+    pub fn guessable_words(&self) -> Box<dyn Iterator<Item = &'static str>> {
+        if let Some(words) = self.custom_words {
+            return Box::new(words.iter().copied());
+        }
+        match self.locale {
+            Locale::En => Box::new(self.mode.guessable_words()),
+            locale => Box::new(bundled_word_lists(locale.code()).1.iter().copied()),
+        }
+    }
+
+    /// Whether `word` can be guessed.
+    #[must_use]
+    pub fn is_valid_word(&self, word: &str) -> bool {
+        if let Some(words) = self.custom_words {
+            return words.contains(&word);
+        }
+        match self.locale {
+            Locale::En => self.mode.is_valid_word(word),
+            locale => bundled_word_lists(locale.code()).1.contains(&word),
+        }
+    }
+}
+
+fn parse_millis(s: &str) -> Result<Duration, String> {
+    match s.parse() {
+        Ok(0) => Err("must be more than 0".to_owned()),
+        Ok(ms) => Ok(Duration::from_millis(ms)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn parse_secs(s: &str) -> Result<Duration, String> {
+    match s.parse() {
+        Ok(0) => Err("must be more than 0".to_owned()),
+        Ok(secs) => Ok(Duration::from_secs(secs)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print statistics about the built-in word lists
+    Analysis {
+        /// Print the most frequent match patterns of the guesses in the
+        /// scores file instead
+        #[arg(long)]
+        match_distribution: bool,
+    },
+    /// Show a sample game in each of the built-in themes
+    Themes,
+    /// Print the results of past games
+    Scores {
+        /// How to print the scores
+        #[arg(long, value_enum, default_value_t)]
+        output_format: OutputFormat,
+    },
+    /// Continue the game that was quit halfway through, or start a new one
+    Resume,
+}
+
+impl Command {
+    /// Name of the command as typed on the command line.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Analysis { .. } => "analysis",
+            Command::Themes => "themes",
+            Command::Scores { .. } => "scores",
+            Command::Resume => "resume",
+        }
+    }
+}
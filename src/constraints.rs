@@ -0,0 +1,188 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::game::{GuessEntry, Match};
+
+/// Something known about the secret word. Positions start at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Constraint {
+    /// The letter is at the position.
+    At(char, usize),
+    /// The letter is in the word, but not at the position.
+    NotAt(char, usize),
+    /// The letter isn't in the word at all.
+    Absent(char),
+}
+
+/// What the guesses tell about the secret word, going further than the marks
+/// of the keyboard: a position known to hold one letter rules out every other
+/// letter from it, and a letter left with a single place to go must be there.
+///
+/// ```
+/// use yawc::{
+///     constraints::{infer_constraints, Constraint::*},
+///     game::{GuessEntry, Match::*},
+/// };
+///
+/// let guess = |word: &str, matches: Vec<_>| GuessEntry::new(word.to_owned(), matches);
+/// let constraints = infer_constraints(&[
+///     guess("axxxx", vec![Correct, Incorrect, Incorrect, Incorrect, Incorrect]),
+///     guess("ebxxx", vec![Incorrect, Misplaced, Incorrect, Incorrect, Incorrect]),
+/// ]);
+/// assert!(constraints.contains(&At('a', 0)));
+/// assert!(constraints.contains(&Absent('x')));
+/// // `b` can't be at 0, which is `a`'s, nor at 1, where it was misplaced
+/// assert!(constraints.contains(&NotAt('b', 0)));
+/// assert!(constraints.contains(&NotAt('b', 1)));
+///
+/// // misplaced at 2, 3 and 4 too, so 1 is the only place left
+/// let constraints = infer_constraints(&[
+///     guess("axbxx", vec![Correct, Incorrect, Misplaced, Incorrect, Incorrect]),
+///     guess("yyyby", vec![Incorrect, Incorrect, Incorrect, Misplaced, Incorrect]),
+///     guess("zzzzb", vec![Incorrect, Incorrect, Incorrect, Incorrect, Misplaced]),
+/// ]);
+/// assert!(constraints.contains(&At('b', 1)));
+/// ```
+#[must_use]
+pub fn infer_constraints(guesses: &[GuessEntry]) -> Vec<Constraint> {
+    let word_len = guesses.first().map_or(0, |g| g.matches.len());
+    let mut known = vec![None; word_len];
+    let mut ruled_out: BTreeMap<char, BTreeSet<usize>> = BTreeMap::new();
+    // fewest times each letter is known to be in the word
+    let mut min_count: BTreeMap<char, usize> = BTreeMap::new();
+    let mut absent = BTreeSet::new();
+
+    for guess in guesses {
+        let mut count: BTreeMap<char, usize> = BTreeMap::new();
+        for ((i, c), &m) in guess.word.chars().enumerate().zip(&guess.matches) {
+            match m {
+                Match::Correct => {
+                    known[i] = Some(c);
+                    *count.entry(c).or_default() += 1;
+                }
+                Match::Misplaced => {
+                    ruled_out.entry(c).or_default().insert(i);
+                    *count.entry(c).or_default() += 1;
+                }
+                Match::Incorrect => {
+                    ruled_out.entry(c).or_default().insert(i);
+                }
+            }
+        }
+        for (c, m) in guess.word.chars().zip(&guess.matches) {
+            if *m == Match::Incorrect && !count.contains_key(&c) {
+                absent.insert(c);
+            }
+        }
+        for (c, n) in count {
+            let min = min_count.entry(c).or_default();
+            *min = (*min).max(n);
+        }
+    }
+
+    // placing a letter frees no position, so this ends after at most
+    // `word_len` rounds
+    loop {
+        for (i, k) in known.iter().enumerate() {
+            if let Some(k) = *k {
+                for &c in min_count.keys().filter(|&&c| c != k) {
+                    ruled_out.entry(c).or_default().insert(i);
+                }
+            }
+        }
+        let mut placed = false;
+        for (&c, &min) in &min_count {
+            let at = known.iter().filter(|&&k| k == Some(c)).count();
+            let free: Vec<_> = (0..word_len)
+                .filter(|&i| {
+                    known[i].is_none() && !ruled_out.get(&c).is_some_and(|r| r.contains(&i))
+                })
+                .collect();
+            if min == at + 1 && free.len() == 1 {
+                known[free[0]] = Some(c);
+                placed = true;
+            }
+        }
+        if !placed {
+            break;
+        }
+    }
+
+    let mut constraints: Vec<_> = known
+        .iter()
+        .enumerate()
+        .filter_map(|(i, k)| k.map(|c| Constraint::At(c, i)))
+        .collect();
+    for (c, positions) in ruled_out {
+        if absent.contains(&c) {
+            continue;
+        }
+        constraints.extend(
+            positions
+                .into_iter()
+                .filter(|&i| known[i] != Some(c))
+                .map(|i| Constraint::NotAt(c, i)),
+        );
+    }
+    constraints.extend(absent.into_iter().map(Constraint::Absent));
+    constraints
+}
+
+/// [`Constraint`]s gathered by letter, for looking them up while drawing.
+///
+/// ```
+/// use yawc::constraints::{Constraint::*, ConstraintSet};
+///
+/// let set = ConstraintSet::new(&[At('a', 0), NotAt('b', 0), NotAt('b', 3), Absent('x')]);
+/// assert_eq!(set.ruled_out('b'), [0, 3]);
+/// assert!(set.ruled_out('x').is_empty());
+/// assert!(set.is_absent('x'));
+/// assert_eq!(set.letter_at(0), Some('a'));
+/// assert_eq!(set.letter_at(1), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ConstraintSet {
+    at: BTreeMap<usize, char>,
+    ruled_out: BTreeMap<char, BTreeSet<usize>>,
+    absent: BTreeSet<char>,
+}
+
+impl ConstraintSet {
+    #[must_use]
+    pub fn new(constraints: &[Constraint]) -> Self {
+        let mut set = Self::default();
+        for &constraint in constraints {
+            match constraint {
+                Constraint::At(c, i) => {
+                    set.at.insert(i, c);
+                }
+                Constraint::NotAt(c, i) => {
+                    set.ruled_out.entry(c).or_default().insert(i);
+                }
+                Constraint::Absent(c) => {
+                    set.absent.insert(c);
+                }
+            }
+        }
+        set
+    }
+
+    /// Positions `c` is in the word but known not to be at, in order.
+    #[must_use]
+    pub fn ruled_out(&self, c: char) -> Vec<usize> {
+        self.ruled_out
+            .get(&c)
+            .map(|r| r.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn is_absent(&self, c: char) -> bool {
+        self.absent.contains(&c)
+    }
+
+    /// The letter known to be at position `i`.
+    #[must_use]
+    pub fn letter_at(&self, i: usize) -> Option<char> {
+        self.at.get(&i).copied()
+    }
+}
@@ -0,0 +1,93 @@
+use std::{io, time::Duration};
+
+use crossterm::{
+    cursor, queue,
+    style::{self, Color, Stylize},
+};
+
+use crate::raw::Terminal;
+
+/// Number of `█` in the bar of a full timer.
+const BAR_WIDTH: u32 = 10;
+
+/// Time left to do something, drawn as `MM:SS` and a bar going from green to
+/// red as it runs out. It knows nothing of the game, which only has to
+/// [`tick`](Self::tick) it and draw it somewhere.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CountdownTimer {
+    pub remaining: Duration,
+    pub total: Duration,
+}
+
+impl CountdownTimer {
+    /// Columns taken by the timer on screen.
+    pub const WIDTH: u16 = 5 + 1 + BAR_WIDTH as u16;
+
+    /// A timer of `total` that just started.
+    #[must_use]
+    pub fn new(total: Duration) -> Self {
+        Self {
+            remaining: total,
+            total,
+        }
+    }
+
+    /// Sets the time left, which can't be more than the total.
+    pub fn tick(&mut self, remaining: Duration) {
+        self.remaining = remaining.min(self.total);
+    }
+
+    /// Fraction of the total time left, from `0.0` to `1.0`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use yawc::countdown::CountdownTimer;
+    ///
+    /// let mut timer = CountdownTimer::new(Duration::from_secs(60));
+    /// assert_eq!(timer.fraction_left(), 1.0);
+    /// timer.tick(Duration::from_secs(15));
+    /// assert_eq!(timer.fraction_left(), 0.25);
+    /// timer.tick(Duration::from_secs(90));
+    /// assert_eq!(timer.fraction_left(), 1.0);
+    /// ```
+    #[must_use]
+    pub fn fraction_left(&self) -> f64 {
+        if self.total.is_zero() {
+            return 0.0;
+        }
+        self.remaining.as_secs_f64() / self.total.as_secs_f64()
+    }
+
+    /// Draws the timer with its left end at `col`, `row`.
+    #[allow(
+        clippy::missing_errors_doc,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn render(&self, term: &mut Terminal, col: u16, row: u16) -> io::Result<()> {
+        let fraction = self.fraction_left();
+        // round up, so that the bar only empties once the time is up
+        let secs = self.remaining.as_millis().div_ceil(1000);
+        let filled = (fraction * f64::from(BAR_WIDTH)).ceil() as usize;
+        let color = Color::Rgb {
+            r: ((1.0 - fraction) * 255.0) as u8,
+            g: (fraction * 200.0) as u8,
+            b: 0,
+        };
+        let bar = format!(
+            "{:02}:{:02} {:<width$}",
+            secs / 60,
+            secs % 60,
+            "█".repeat(filled),
+            width = BAR_WIDTH as usize
+        );
+        queue!(
+            term,
+            cursor::SavePosition,
+            cursor::MoveTo(col, row),
+            style::PrintStyledContent(bar.with(color)),
+            cursor::RestorePosition,
+        )?;
+        io::Write::flush(term)
+    }
+}
@@ -0,0 +1,48 @@
+use std::{
+    fs, panic,
+    path::PathBuf,
+    sync::{Arc, Mutex, TryLockError},
+};
+
+use crate::stats::{scores_path, Snapshot};
+
+/// Where the game is saved when yawc panics: `crash`, next to the scores
+/// file.
+#[must_use]
+pub fn crash_snapshot_path() -> Option<PathBuf> {
+    Some(scores_path()?.with_file_name("crash"))
+}
+
+/// Makes a panic save `snapshot`, the game as it was last seen, to
+/// `snapshot_path` before the panic message is printed, so that it can be
+/// picked up again with [`take_crash_snapshot`] on the next launch.
+pub fn install_panic_hook(snapshot_path: PathBuf, snapshot: Arc<Mutex<Option<Snapshot>>>) {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        // the panic may have happened while the snapshot was being updated,
+        // waiting for it would never end
+        let snapshot = match snapshot.try_lock() {
+            Ok(snapshot) => snapshot.clone(),
+            Err(TryLockError::Poisoned(e)) => e.into_inner().clone(),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        if let Some(snapshot) = snapshot {
+            if let Some(dir) = snapshot_path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+            let _ = fs::write(&snapshot_path, format!("{snapshot}\n"));
+        }
+        previous(info);
+    }));
+}
+
+/// The game saved by a panic last time, if there's one worth picking up,
+/// removing it so that it's only offered once.
+#[must_use]
+pub fn take_crash_snapshot() -> Option<Snapshot> {
+    let path = crash_snapshot_path()?;
+    let contents = fs::read_to_string(&path).ok()?;
+    let _ = fs::remove_file(&path);
+    Snapshot::parse(contents.trim_end())
+        .filter(|s| s.is_in_progress() && !(s.guesses.is_empty() && s.current_guess.is_empty()))
+}
@@ -0,0 +1,157 @@
+use std::{fmt, str::FromStr};
+
+use crate::game::{GuessEntry, GuessHistory, Match};
+
+/// A square two words of a crossword share: letter `pos_a` of word
+/// `word_a_idx` is letter `pos_b` of word `word_b_idx`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Intersection {
+    pub word_a_idx: usize,
+    pub pos_a: usize,
+    pub word_b_idx: usize,
+    pub pos_b: usize,
+}
+
+/// Letter found at `pos` of a word, i.e. marked correct there in one of
+/// `guesses`.
+fn known_letter(guesses: &[GuessEntry], pos: usize) -> Option<char> {
+    guesses.iter().find_map(|g| {
+        let (c, m) = g.word.chars().zip(g.matches.iter()).nth(pos)?;
+        (*m == Match::Correct).then_some(c)
+    })
+}
+
+/// Whether `guess_b` has the letters already found in word A, by
+/// `guesses_a`, where `intersections` cross it. Squares of word A that
+/// weren't found yet allow any letter.
+///
+/// ```
+/// use yawc::{
+///     crossword::{check_crossword_constraint, Intersection},
+///     game::{GuessEntry, Match::*},
+/// };
+///
+/// let guesses_a = [GuessEntry::new(
+///     "crane".to_owned(),
+///     vec![Incorrect, Incorrect, Correct, Incorrect, Misplaced],
+/// )];
+/// // the A of word A is the 4th letter of word B
+/// let a = Intersection { word_a_idx: 0, pos_a: 2, word_b_idx: 1, pos_b: 3 };
+/// assert!(check_crossword_constraint(&guesses_a, "ideal", &[a]));
+/// assert!(!check_crossword_constraint(&guesses_a, "slate", &[a]));
+///
+/// // nothing is known about the E yet
+/// let e = Intersection { word_a_idx: 0, pos_a: 4, word_b_idx: 1, pos_b: 0 };
+/// assert!(check_crossword_constraint(&guesses_a, "slate", &[e]));
+/// ```
+#[must_use]
+pub fn check_crossword_constraint(
+    guesses_a: &[GuessEntry],
+    guess_b: &str,
+    intersections: &[Intersection],
+) -> bool {
+    intersections.iter().all(|i| {
+        known_letter(guesses_a, i.pos_a).is_none_or(|c| guess_b.chars().nth(i.pos_b) == Some(c))
+    })
+}
+
+/// Checks guesses against the letters found in the words they cross, the
+/// words being those of a session in the order they're played.
+///
+/// Given with `--crossword` as `A:I=B:J` for each crossing, letter `I` of
+/// word `A` being letter `J` of word `B`, all counting from 1.
+///
+/// ```
+/// use yawc::crossword::{CrosswordChecker, Intersection};
+///
+/// let checker: CrosswordChecker = "1:3=2:4 2:1=3:5".parse().unwrap();
+/// assert_eq!(
+///     checker.intersections,
+///     [
+///         Intersection { word_a_idx: 0, pos_a: 2, word_b_idx: 1, pos_b: 3 },
+///         Intersection { word_a_idx: 1, pos_a: 0, word_b_idx: 2, pos_b: 4 },
+///     ]
+/// );
+/// assert!("1:3".parse::<CrosswordChecker>().is_err());
+/// assert!("1:0=2:4".parse::<CrosswordChecker>().is_err());
+/// assert!("1:3=1:4".parse::<CrosswordChecker>().is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CrosswordChecker {
+    pub intersections: Vec<Intersection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCrosswordError(String);
+
+impl fmt::Display for ParseCrosswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid crossing {:?}, expected word:letter=word:letter like 1:3=2:4, of two different words",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseCrosswordError {}
+
+impl FromStr for CrosswordChecker {
+    type Err = ParseCrosswordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let intersections = s
+            .split_whitespace()
+            .map(|token| {
+                let error = || ParseCrosswordError(token.to_owned());
+                // from 1 to from 0
+                let square = |square: &str| {
+                    let (word, letter) = square.split_once(':')?;
+                    let word = word.parse::<usize>().ok()?.checked_sub(1)?;
+                    let letter = letter.parse::<usize>().ok()?.checked_sub(1)?;
+                    Some((word, letter))
+                };
+                let (a, b) = token.split_once('=').ok_or_else(error)?;
+                let ((word_a_idx, pos_a), (word_b_idx, pos_b)) =
+                    square(a).zip(square(b)).ok_or_else(error)?;
+                if word_a_idx == word_b_idx {
+                    return Err(error());
+                }
+                Ok(Intersection {
+                    word_a_idx,
+                    pos_a,
+                    word_b_idx,
+                    pos_b,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { intersections })
+    }
+}
+
+impl CrosswordChecker {
+    /// Why `guess` can't be word `word_idx`, given the guesses made for every
+    /// word so far, if it can't. Meant for the status bar.
+    #[must_use]
+    pub fn violation(
+        &self,
+        guesses: &[GuessHistory],
+        word_idx: usize,
+        guess: &str,
+    ) -> Option<String> {
+        self.intersections
+            .iter()
+            .filter(|i| i.word_b_idx == word_idx)
+            .find_map(|i| {
+                let c = known_letter(guesses.get(i.word_a_idx)?, i.pos_a)?;
+                (guess.chars().nth(i.pos_b) != Some(c)).then(|| {
+                    format!(
+                        "Letter {} must be {}, where word {} crosses it!",
+                        i.pos_b + 1,
+                        c.to_uppercase(),
+                        i.word_a_idx + 1
+                    )
+                })
+            })
+    }
+}
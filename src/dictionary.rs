@@ -0,0 +1,138 @@
+use std::{collections::HashSet, fs, io, path::Path};
+
+use crate::{config::Config, loader::parse_word_list};
+
+/// Which words can be guessed. The secret word itself is drawn by a
+/// [`SecretWordGenerator`](crate::generator::SecretWordGenerator), so that
+/// `--seed`, `--daily` and `--puzzle-set` work whatever the dictionary.
+///
+/// ```
+/// use yawc::dictionary::DictionaryBackend;
+///
+/// /// Accepts anything 5 letters long.
+/// struct MockDictionary;
+///
+/// impl DictionaryBackend for MockDictionary {
+///     fn is_valid(&self, word: &str) -> bool {
+///         word.len() == 5 && word.bytes().all(|b| b.is_ascii_lowercase())
+///     }
+/// }
+///
+/// let dictionary = MockDictionary;
+/// assert!(dictionary.is_valid("zzzzz"));
+/// assert!(!dictionary.is_valid("zzzz"));
+/// ```
+pub trait DictionaryBackend {
+    /// Whether `word` can be guessed.
+    fn is_valid(&self, word: &str) -> bool;
+}
+
+/// The word lists built in or given on the command line: those of the mode,
+/// of `--locale`, read from `--word-list` or downloaded with
+/// `--wordlist-url`.
+pub struct StaticDictionary {
+    guessable: HashSet<&'static str>,
+}
+
+impl StaticDictionary {
+    #[must_use]
+    pub fn for_config(config: &Config) -> Self {
+        Self {
+            guessable: config.guessable_words().collect(),
+        }
+    }
+}
+
+impl DictionaryBackend for StaticDictionary {
+    fn is_valid(&self, word: &str) -> bool {
+        self.guessable.contains(word)
+    }
+}
+
+/// Words read from a file, one per line, as with
+/// [`parse_word_list`]. Blank lines are skipped.
+pub struct FileDictionary {
+    words: HashSet<String>,
+}
+
+impl FileDictionary {
+    /// Reads the words in the file at `path`, which must all be `word_len`
+    /// lowercase letters long.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be read, or [`parse_word_list`] rejects it.
+    pub fn open(path: &Path, word_len: usize) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self {
+            words: parse_word_list(&contents, word_len, |_| {})?
+                .into_iter()
+                .collect(),
+        })
+    }
+}
+
+impl DictionaryBackend for FileDictionary {
+    fn is_valid(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::game::{Game, GameError};
+
+    /// Accepts anything 5 letters long.
+    struct MockDictionary;
+
+    impl DictionaryBackend for MockDictionary {
+        fn is_valid(&self, word: &str) -> bool {
+            word.chars().count() == 5
+        }
+    }
+
+    #[test]
+    fn game_rejects_made_up_words_by_default() {
+        assert!(matches!(
+            Game::headless(Config::default(), "crane")
+                .unwrap()
+                .inject_guess("zzzzz"),
+            Err(GameError::InvalidWord(_))
+        ));
+    }
+
+    #[test]
+    fn game_accepts_made_up_words_from_mock() {
+        let mut game = Game::headless(Config::default(), "crane")
+            .unwrap()
+            .with_dictionary(Box::new(MockDictionary));
+        assert_eq!(game.inject_guess("zzzzz").unwrap(), None);
+        assert_eq!(game.inject_guess("qxqxq").unwrap(), None);
+        assert!(game.inject_guess("zzzz").is_err());
+    }
+
+    #[test]
+    fn static_dictionary_uses_the_word_lists() {
+        let dictionary = StaticDictionary::for_config(&Config::default());
+        assert!(dictionary.is_valid("crane"));
+        assert!(!dictionary.is_valid("zzzzz"));
+    }
+
+    #[test]
+    fn file_dictionary_reads_words() {
+        let path = env::temp_dir().join(format!("yawc-test-words-{}", std::process::id()));
+        fs::write(&path, "crane\n\nslate\n").unwrap();
+        let dictionary = FileDictionary::open(&path, 5).unwrap();
+        assert!(dictionary.is_valid("crane") && dictionary.is_valid("slate"));
+        assert!(!dictionary.is_valid("pious"));
+
+        fs::write(&path, "crane\nCRANE\n").unwrap();
+        assert!(FileDictionary::open(&path, 5).is_err());
+        fs::write(&path, "\n").unwrap();
+        assert!(FileDictionary::open(&path, 5).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
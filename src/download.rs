@@ -0,0 +1,140 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::PathBuf,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use crate::{loader::parse_word_list, status::SPINNER};
+
+/// How long a downloaded list is used before it's downloaded again.
+const CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long to wait for the server before giving up on the download.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where the list downloaded from `url` is cached:
+/// `$XDG_CACHE_HOME/yawc/wordlists/<hash of url>`, falling back to
+/// `~/.cache/yawc/wordlists/<hash of url>`.
+fn cache_path(url: &str) -> Option<PathBuf> {
+    let cache = env::var_os("XDG_CACHE_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))?;
+    Some(
+        cache
+            .join("yawc")
+            .join("wordlists")
+            .join(format!("{:016x}", fnv1a(url.as_bytes()))),
+    )
+}
+
+/// 64-bit FNV-1a hash of `bytes`, which unlike the standard library's hasher
+/// stays the same from one Rust release to the next, so the cache does too.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The cached list for `url`, unless there's none or it's too old.
+fn read_cache(url: &str) -> Option<String> {
+    let path = cache_path(url)?;
+    let age = fs::metadata(&path).ok()?.modified().ok()?.elapsed().ok()?;
+    if age > CACHE_TTL {
+        return None;
+    }
+    fs::read_to_string(path).ok()
+}
+
+fn write_cache(url: &str, list: &str) -> io::Result<()> {
+    let path = cache_path(url)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, list)
+}
+
+/// Downloads `url`, showing a spinner on stderr until it's done.
+fn download(url: &str) -> Result<String, String> {
+    let (sender, receiver) = mpsc::channel();
+    let agent = ureq::AgentBuilder::new().timeout(TIMEOUT).build();
+    let request = agent.get(url);
+    thread::spawn(move || {
+        let body = request
+            .call()
+            .map_err(|e| e.to_string())
+            .and_then(|response| response.into_string().map_err(|e| e.to_string()));
+        sender.send(body).ok();
+    });
+
+    let mut stderr = io::stderr();
+    let mut frames = 0;
+    let body = loop {
+        match receiver.recv_timeout(Duration::from_millis(100)) {
+            Ok(body) => break body,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let frame = SPINNER[frames % SPINNER.len()];
+                write!(stderr, "\r{frame} Downloading the word list...").ok();
+                frames += 1;
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                break Err("the download stopped unexpectedly".to_owned());
+            }
+        }
+    };
+    if frames > 0 {
+        write!(stderr, "\r\x1b[K").ok();
+    }
+    body
+}
+
+/// Words of `word_len` letters from the newline-separated list at `url`,
+/// downloaded at most once a day and checked like any other word list with
+/// [`parse_word_list`], whatever their case. Prints a warning and returns [`None`] if
+/// the list can't be downloaded or isn't valid, so that the built-in one can
+/// be used instead.
+#[must_use]
+pub fn fetch_word_list(url: &str, word_len: usize) -> Option<&'static [&'static str]> {
+    let cached = read_cache(url);
+    let list = match cached.clone().map_or_else(|| download(url), Ok) {
+        Ok(list) => list,
+        Err(e) => {
+            eprintln!("Warning: could not download the word list, using the built-in one: {e}");
+            return None;
+        }
+    };
+    let words = match parse_word_list(&list.to_lowercase(), word_len, |_| {}) {
+        Ok(words) => words,
+        Err(e) => {
+            eprintln!("Warning: invalid word list, using the built-in one: {e}");
+            return None;
+        }
+    };
+    if cached.is_none() {
+        if let Err(e) = write_cache(url, &list) {
+            eprintln!("Warning: could not cache the word list: {e}");
+        }
+    }
+    // the words are used for the rest of the program, like the built-in ones
+    let words: Vec<&'static str> = words
+        .into_iter()
+        .map(|w| &*Box::leak(w.into_boxed_str()))
+        .collect();
+    Some(Vec::leak(words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a_matches_the_reference_values() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
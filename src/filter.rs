@@ -0,0 +1,300 @@
+use crate::{
+    clue::Clue,
+    game::{GuessEntry, Match},
+};
+
+/// Longest word a filter can handle, that of [`Mode::Plus`](crate::mode::Mode::Plus).
+const MAX_WORD_LEN: usize = 7;
+
+/// Accented letters of the bundled word lists, numbered after `a..=z`.
+const ACCENTED: &str = "àâäáãåçéèêëíìîïñóòôöõúùûüýÿßæœ";
+
+/// Number of letters a filter knows about, one per bit of its bitmasks.
+const LETTERS: usize = 64;
+
+/// Constraints on the secret word collected from the guesses so far, stored
+/// as letter bitmasks (bit 0 is `a`) so checking a word is a handful of
+/// integer operations. Letters are only counted for the few whose number
+/// matters: those a guess showed to be in the word more than once, or to be
+/// in it only as many times as they were marked correct or misplaced.
+///
+/// For guesses marked by [`check_word`](crate::game::check_word), a word
+/// passes exactly when it would get the same marks for every guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WordFilter {
+    /// Length of the guesses, and so of the secret word, `0` if there are
+    /// none yet.
+    len: usize,
+    /// Letters known to be at each position.
+    required_positions: [Option<u8>; MAX_WORD_LEN],
+    /// Letters known not to be at each position.
+    forbidden_positions: [u64; MAX_WORD_LEN],
+    /// Letters that must appear somewhere in the word.
+    required_anywhere: u64,
+    /// Letters that don't appear in the word at all.
+    forbidden: u64,
+    /// Letters with a count to check against `min_counts` and `max_counts`.
+    counted: u64,
+    /// Fewest times each letter appears in the word.
+    min_counts: [u8; LETTERS],
+    /// Most times each letter appears in the word.
+    max_counts: [u8; LETTERS],
+}
+
+impl Default for WordFilter {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            required_positions: [None; MAX_WORD_LEN],
+            forbidden_positions: [0; MAX_WORD_LEN],
+            required_anywhere: 0,
+            forbidden: 0,
+            counted: 0,
+            min_counts: [0; LETTERS],
+            max_counts: [u8::MAX; LETTERS],
+        }
+    }
+}
+
+/// Bit of `c` in the bitmasks of a filter, or `None` for a letter it doesn't
+/// know about, like those of non-Latin alphabets.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn letter_index(c: char) -> Option<u8> {
+    if c.is_ascii_lowercase() {
+        Some(c as u8 - b'a')
+    } else {
+        ACCENTED.chars().position(|a| a == c).map(|i| 26 + i as u8)
+    }
+}
+
+impl WordFilter {
+    #[must_use]
+    pub fn from_guesses(guesses: &[GuessEntry]) -> Self {
+        let mut filter = Self::default();
+        for entry in guesses {
+            filter.len = entry.matches.len();
+            // letters marked correct or misplaced somewhere in this guess;
+            // an incorrect mark for one of them only says there are no more
+            // of it
+            let mut present = 0u64;
+            let mut capped = 0u64;
+            let mut counts = [0u8; LETTERS];
+            for (c, m) in entry.word.chars().zip(entry.matches.iter().copied()) {
+                match (letter_index(c), m) {
+                    (Some(i), Match::Correct | Match::Misplaced) => {
+                        present |= 1 << i;
+                        counts[usize::from(i)] += 1;
+                    }
+                    (Some(i), Match::Incorrect) => capped |= 1 << i,
+                    (None, _) => {}
+                }
+            }
+            for i in bits(present) {
+                let n = counts[i];
+                if n > 1 {
+                    filter.min_counts[i] = filter.min_counts[i].max(n);
+                    filter.counted |= 1 << i;
+                }
+                if capped & (1 << i) != 0 {
+                    filter.max_counts[i] = filter.max_counts[i].min(n);
+                    filter.counted |= 1 << i;
+                }
+            }
+            for (pos, (c, m)) in entry
+                .word
+                .chars()
+                .zip(entry.matches.iter().copied())
+                .enumerate()
+            {
+                let Some(i) = letter_index(c) else {
+                    continue;
+                };
+                let bit = 1u64 << i;
+                match m {
+                    Match::Correct => filter.required_positions[pos] = Some(i),
+                    Match::Misplaced => filter.forbidden_positions[pos] |= bit,
+                    Match::Incorrect if present & bit != 0 => {
+                        filter.forbidden_positions[pos] |= bit;
+                    }
+                    Match::Incorrect => filter.forbidden |= bit,
+                }
+            }
+            filter.required_anywhere |= present;
+        }
+        filter
+    }
+
+    /// Adds the constraints of `clues`, known before any guess was made.
+    /// Like with guesses, a letter that isn't in the word according to one
+    /// clue but is according to another is only ruled out at its position.
+    #[must_use]
+    pub fn with_clues(mut self, clues: &[Clue]) -> Self {
+        let mut present = 0u64;
+        for clue in clues {
+            if let (Some(i), Match::Correct | Match::Misplaced) =
+                (letter_index(clue.letter), clue.kind)
+            {
+                present |= 1 << i;
+            }
+        }
+        for clue in clues.iter().filter(|c| c.position < MAX_WORD_LEN) {
+            let Some(i) = letter_index(clue.letter) else {
+                continue;
+            };
+            let bit = 1u64 << i;
+            match clue.kind {
+                Match::Correct => self.required_positions[clue.position] = Some(i),
+                Match::Misplaced => self.forbidden_positions[clue.position] |= bit,
+                Match::Incorrect if present & bit != 0 => {
+                    self.forbidden_positions[clue.position] |= bit;
+                }
+                Match::Incorrect => self.forbidden |= bit,
+            }
+        }
+        self.required_anywhere |= present;
+        self
+    }
+
+    /// Whether `word` satisfies every constraint.
+    #[must_use]
+    pub fn matches(&self, word: &str) -> bool {
+        let len = word.chars().count();
+        if len > MAX_WORD_LEN || (self.len != 0 && len != self.len) {
+            return false;
+        }
+        let mut letters = 0u64;
+        let mut counts = [0u8; LETTERS];
+        for (pos, c) in word.chars().enumerate() {
+            let Some(i) = letter_index(c) else {
+                return false;
+            };
+            let bit = 1u64 << i;
+            if self.required_positions[pos].is_some_and(|r| r != i)
+                || self.forbidden_positions[pos] & bit != 0
+            {
+                return false;
+            }
+            if self.counted & bit != 0 {
+                counts[usize::from(i)] += 1;
+            }
+            letters |= bit;
+        }
+        letters & self.forbidden == 0
+            && letters & self.required_anywhere == self.required_anywhere
+            && bits(self.counted)
+                .all(|i| (self.min_counts[i]..=self.max_counts[i]).contains(&counts[i]))
+    }
+}
+
+/// Indices of the bits set in `mask`, lowest first.
+fn bits(mut mask: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        (mask != 0).then(|| {
+            let i = mask.trailing_zeros() as usize;
+            mask &= mask - 1;
+            i
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+    use super::*;
+    use crate::{game::check_word, words::WORDS};
+
+    fn guess(secret: &str, word: &str) -> GuessEntry {
+        GuessEntry::new(word.to_owned(), check_word(secret, word).to_vec())
+    }
+
+    /// Whether `word` gets the same marks as the secret word did for every
+    /// guess, the slow way.
+    fn consistent(word: &str, guesses: &[GuessEntry]) -> bool {
+        guesses
+            .iter()
+            .all(|g| check_word(word, &g.word)[..] == g.matches[..])
+    }
+
+    #[test]
+    fn at_least_as_many_as_marked() {
+        // all three `e`s are correct or misplaced
+        let guesses = [guess("geese", "eerie")];
+        let filter = WordFilter::from_guesses(&guesses);
+        assert!(filter.matches("geese"));
+        // has `e`s where they're known to be, but only two of them
+        assert!(!filter.matches("hedge"));
+        assert!(!consistent("hedge", &guesses));
+    }
+
+    #[test]
+    fn no_more_than_marked() {
+        // one `l` correct, the other incorrect, so there's only one
+        let guesses = [guess("plant", "llama")];
+        let filter = WordFilter::from_guesses(&guesses);
+        assert!(filter.matches("plant"));
+        // no `l` where one was marked incorrect, but two of them
+        assert!(!filter.matches("flail"));
+        assert!(!consistent("flail", &guesses));
+    }
+
+    #[test]
+    fn same_as_checking_every_word() {
+        let mut rng = StdRng::seed_from_u64(125);
+        for _ in 0..100 {
+            let secret = WORDS.choose(&mut rng).unwrap();
+            let guesses: Vec<_> = WORDS
+                .choose_multiple(&mut rng, 3)
+                .map(|word| guess(secret, word))
+                .collect();
+            for n in 1..=guesses.len() {
+                let filter = WordFilter::from_guesses(&guesses[..n]);
+                for word in WORDS {
+                    assert_eq!(
+                        filter.matches(word),
+                        consistent(word, &guesses[..n]),
+                        "{word} against {:?}",
+                        &guesses[..n],
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_as_checking_every_word_with_repeated_letters() {
+        let repeated: Vec<_> = WORDS
+            .iter()
+            .filter(|w| {
+                let mut letters: Vec<_> = w.chars().collect();
+                letters.sort_unstable();
+                letters.dedup();
+                letters.len() < 5
+            })
+            .collect();
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..100 {
+            let secret = repeated.choose(&mut rng).unwrap();
+            let guesses: Vec<_> = repeated
+                .choose_multiple(&mut rng, 2)
+                .map(|word| guess(secret, word))
+                .collect();
+            let filter = WordFilter::from_guesses(&guesses);
+            for word in WORDS {
+                assert_eq!(filter.matches(word), consistent(word, &guesses), "{word}");
+            }
+        }
+    }
+
+    #[test]
+    fn secret_word_always_passes() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for secret in WORDS.choose_multiple(&mut rng, 200) {
+            let guesses: Vec<_> = WORDS
+                .choose_multiple(&mut rng, 5)
+                .map(|word| guess(secret, word))
+                .collect();
+            assert!(WordFilter::from_guesses(&guesses).matches(secret));
+        }
+    }
+}
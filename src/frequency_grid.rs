@@ -0,0 +1,79 @@
+use std::fmt;
+
+use crossterm::{
+    cursor,
+    style::{self, Stylize},
+};
+
+use crate::{analysis::positional_frequencies, theme::uppercase_letter};
+
+/// Box showing the most common letters at each position of the remaining
+/// candidates, drawn with its top left corner at the cursor.
+pub struct LetterFrequencyGrid {
+    columns: [[(char, f32); 3]; 5],
+}
+
+impl LetterFrequencyGrid {
+    #[must_use]
+    pub fn new(candidates: &[&str]) -> Self {
+        Self {
+            columns: positional_frequencies(candidates),
+        }
+    }
+
+    /// Size of the box in columns and rows.
+    pub const WIDTH: u16 = 46;
+    pub const HEIGHT: u16 = 7;
+}
+
+impl fmt::Display for LetterFrequencyGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = |left, middle, right| {
+            let mut line = String::from(left);
+            for i in 0..5 {
+                line.push_str("────────");
+                line.push(if i == 4 { right } else { middle });
+            }
+            line
+        };
+        let header: String = (1..=5).map(|i| format!("   {i}    │")).collect();
+        for line in [
+            line('┌', '┬', '┐'),
+            format!("│{header}"),
+            line('├', '┼', '┤'),
+        ] {
+            write!(
+                f,
+                "{}{line}{}{}",
+                cursor::SavePosition,
+                cursor::RestorePosition,
+                cursor::MoveDown(1)
+            )?;
+        }
+        for rank in 0..3 {
+            write!(f, "{}│", cursor::SavePosition)?;
+            for column in &self.columns {
+                let (c, freq) = column[rank];
+                let cell = if freq > 0.0 {
+                    format!(" {} {:>3.0}% ", uppercase_letter(c), freq * 100.0)
+                } else {
+                    " ".repeat(8)
+                };
+                // common letters stand out, rare ones fade away
+                let cell = match freq {
+                    f if f >= 0.5 => cell.green(),
+                    f if f >= 0.2 => cell.yellow(),
+                    _ => cell.dark_grey(),
+                };
+                write!(f, "{cell}│")?;
+            }
+            write!(f, "{}{}", cursor::RestorePosition, cursor::MoveDown(1))?;
+        }
+        write!(
+            f,
+            "{}{}",
+            cursor::SavePosition,
+            style::Print(line('└', '┴', '┘'))
+        )
+    }
+}
@@ -1,8 +1,15 @@
 use std::{
     cmp,
+    collections::VecDeque,
+    fmt,
     io::{self, Write},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex, PoisonError,
+    },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use crossterm::{
@@ -10,77 +17,697 @@ use crossterm::{
     event::{self, KeyCode, KeyModifiers},
     execute,
     style::{self, Stylize},
-    terminal::{self, size},
+    terminal,
 };
-use rand::{prelude::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, thread_rng, Rng};
+use serde::Serialize;
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    keyboard::Keyboard,
-    raw::Terminal,
+    allocations::allocations,
+    analysis::word_frequency_tier,
+    animation::{confetti_animation, easter_egg_animation, EASTER_EGG_WORD},
+    clue::Clue,
+    config::Config,
+    constraints::{infer_constraints, ConstraintSet},
+    countdown::CountdownTimer,
+    crash::{crash_snapshot_path, install_panic_hook},
+    dictionary::{DictionaryBackend, StaticDictionary},
+    filter::WordFilter,
+    frequency_grid::LetterFrequencyGrid,
+    generator::{self, FixedGenerator, SecretWordGenerator},
+    history::HistoryViewer,
+    keyboard::{keyboard_width, Keyboard, KeyboardPosition, SmartKeyboard},
+    locale::Locale,
+    logger::{GameEvent, GameLogger},
+    mode::Mode,
+    raw::{Terminal, TerminalInfo, TerminalSize},
+    replay::ReplayController,
+    solver::{best_completion, bot_analysis, compute_entropy, suggest_candidate},
+    sound,
+    stats::{
+        self, compute_score, compute_session_summary, ScoreRecord, Snapshot, TournamentRecord,
+        MAX_GUESSES,
+    },
+    status::{draw_seed_badge, osc52_copy, osc8_link, supports_hyperlinks, StatusMessage, SPINNER},
+    theme::{
+        next_correct_color, next_misplaced_color, uppercase_letter, ColorSupport, EmojiStyle,
+        THEMES,
+    },
+    tips::random_tip,
+    tournament::{TournamentBracket, TournamentGame, PLAYERS, ROUNDS},
+    word_graph::WordGraph,
     words::{ACCEPTABLE, WORDS},
 };
 
+/// Set to make [`Game::main_loop`] return as soon as it's done with what it's
+/// doing, for programs running a game alongside other things. It's cleared
+/// again once the game stops. See [`interrupt_game`].
+pub static INTERRUPT_FLAG: AtomicBool = AtomicBool::new(false);
+
+/// Stops the game being played, as if the player quit it.
+///
+/// The terminal is in raw mode while playing, so `Ctrl+C` in it is a key
+/// press rather than a signal, but other ways of stopping the host program
+/// can end the game too. For example with a handler from the `ctrlc` crate:
+///
+/// ```ignore
+/// use std::thread;
+///
+/// use yawc::game::{interrupt_game, Game};
+///
+/// ctrlc::set_handler(interrupt_game)?;
+/// let game = thread::spawn(|| Game::new()?.main_loop());
+/// // ... `kill -INT` the program, and the game ends and restores the terminal
+/// game.join().unwrap()?;
+/// ```
+pub fn interrupt_game() {
+    INTERRUPT_FLAG.store(true, Ordering::Relaxed);
+}
+
+/// How long [`Game::guess_info`] keeps its result.
+const GUESS_INFO_TTL: Duration = Duration::from_millis(200);
+
+/// Rows taken by the keyboard and the gap under it with
+/// `--keyboard-position top`.
+const TOP_KEYBOARD_ROWS: u16 = 8;
+
+/// How often the replay checks for keys and moves on.
+const REPLAY_TICK: Duration = Duration::from_millis(50);
+
+/// Size of the pretend terminal drawn to by `--benchmark-fps` and
+/// [`Game::headless`].
+const HEADLESS_SIZE: TerminalSize = TerminalSize {
+    width: 80,
+    height: 30,
+};
+
+/// Candidate lists at least this long, which only custom word lists get to,
+/// are filtered on another thread while a spinner shows that the game isn't
+/// stuck.
+const BACKGROUND_FILTER_LEN: usize = 20_000;
+
 pub struct Game<'w> {
     secret_word: &'w str,
-    guesses: Vec<(String, [Match; 5])>,
+    guesses: GuessHistory,
     guess: String,
+    /// Secret words still consistent with all the guesses so far.
+    candidates: Vec<&'static str>,
     keyboard: Keyboard,
     term: Terminal,
+    /// Where the size of the screen comes from instead of `term`, see
+    /// [`Game::with_terminal_info`].
+    terminal_info: Option<Box<dyn TerminalInfo>>,
+    tournament: Option<TournamentGame>,
+    /// Players of `--tournament-4`, set once they typed in their names.
+    bracket: Option<TournamentBracket>,
+    /// Picks the secret words outside of tournaments.
+    generator: Box<dyn SecretWordGenerator>,
+    /// Decides which guesses are words.
+    dictionary: Box<dyn DictionaryBackend>,
+    logger: Option<GameLogger>,
+    /// Last known size of the terminal.
+    size: TerminalSize,
+    /// Set when the terminal was resized but the screen hasn't been redrawn
+    /// yet, so that animations can stop drawing at stale coordinates.
+    pending_resize: bool,
+    /// Whether the letters that weren't tried yet are shown under the
+    /// keyboard, toggled with `Ctrl+H`.
+    show_untried: bool,
+    /// Whether the earlier guesses are dimmed, starting as `--focus` says and
+    /// toggled with `Ctrl+O`.
+    focus: bool,
+    /// Events read while an animation was checking for resizes, to be
+    /// handled once it's done.
+    deferred_events: VecDeque<event::Event>,
+    /// Candidate giving the most information, with how many bits, once
+    /// `pending_suggestion` has worked it out.
+    suggestion: Option<(&'static str, f64)>,
+    pending_suggestion: Option<mpsc::Receiver<(&'static str, f64)>>,
+    /// Set to have the thread behind `pending_suggestion` give up.
+    suggestion_cancel: Arc<AtomicBool>,
+    /// Row of the screen the suggestion goes on.
+    suggestion_row: u16,
+    /// Clues of `--clue`, kept until the first game is over.
+    clues: Vec<Clue>,
+    /// Games started so far, counting from 0, for the colours of `--rainbow`.
+    game_number: usize,
+    /// Last event handled by the game loop, for the debug panel.
+    last_event: Option<String>,
+    /// Games finished since the program started, for the summary shown when
+    /// the player quits with `Ctrl+C`.
+    session: Vec<GameRecord>,
+    session_start: Instant,
+    /// Whether the player quit with `Ctrl+C` in the middle of a game.
+    quit_mid_game: bool,
+    /// Time left for the current guess with `--timeout-per-guess`, shown in
+    /// the top right corner of the grid.
+    countdown: Option<CountdownTimer>,
+    /// When the session started and how long it may last, with
+    /// `--max-time-per-session`.
+    session_timer: Option<(Instant, Duration)>,
+    /// Colours the terminal can show, which the theme is made to fit.
+    color_support: ColorSupport,
+    /// Guess [`Game::guess_info`] was last worked out for, when, and what it
+    /// was.
+    guess_info: Option<(String, Instant, f64)>,
+    /// The game as of the last event, for the panic hook to save.
+    crash_snapshot: Arc<Mutex<Option<Snapshot>>>,
+    /// Number of secret words chained so far with `--wordchain`.
+    chain: usize,
+    /// Letter no secret word started with, which broke the chain before
+    /// this game.
+    chain_break: Option<char>,
+    config: Config,
 }
 
 impl Game<'_> {
     #[allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
     pub fn new() -> io::Result<Self> {
-        Ok(Self {
-            secret_word: WORDS.choose(&mut thread_rng()).unwrap(),
+        Self::new_with_config(Config::default())
+    }
+
+    /// Checks `config` for invalid values and conflicting options, returning
+    /// every problem found.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn validate_config(config: &Config) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if let Some(word) = &config.word {
+            if word.chars().count() != 5 {
+                errors.push(format!("--word {word:?} must be 5 letters long"));
+            } else if !config.is_valid_word(word) {
+                errors.push(format!("--word {word:?} is not in the word list"));
+            }
+        }
+        if let Some(word) = &config.first_guess {
+            let len = config.mode.word_len();
+            if word.chars().count() != len {
+                errors.push(format!("--first-guess {word:?} must be {len} letters long"));
+            } else if !config.is_valid_word(word) {
+                errors.push(format!("--first-guess {word:?} is not in the word list"));
+            }
+        }
+        // modes that replace the regular game and so exclude each other
+        let modes: Vec<_> = [
+            (config.batch, "--batch"),
+            (config.profile_solver, "--profile-solver"),
+            (config.tournament, "--tournament"),
+            (config.tournament_4, "--tournament-4"),
+            (config.benchmark_fps.is_some(), "--benchmark-fps"),
+        ]
+        .into_iter()
+        .filter_map(|(set, flag)| set.then_some(flag))
+        .collect();
+        if modes.len() > 1 {
+            errors.push(format!("{} can't be used together", modes.join(", ")));
+        }
+        // those modes only know about 5-letter words
+        if config.mode == Mode::Plus {
+            for flag in modes.iter().filter(|&&flag| flag != "--tournament-4") {
+                errors.push(format!("{flag} can't be used with --mode plus"));
+            }
+        }
+        // and only the English ones, apart from batch mode and the bracket
+        let english_only = || {
+            modes
+                .iter()
+                .filter(|&&flag| flag != "--batch" && flag != "--tournament-4")
+        };
+        if config.locale != Locale::En {
+            if config.mode == Mode::Plus {
+                errors.push("--mode plus can only be used with --locale en".to_owned());
+            }
+            for flag in english_only() {
+                errors.push(format!("{flag} can only be used with --locale en"));
+            }
+        }
+        #[cfg(feature = "download")]
+        if config.wordlist_url.is_some() {
+            for flag in english_only() {
+                errors.push(format!("{flag} can't be used with --wordlist-url"));
+            }
+        }
+        if let Some(clues) = &config.clue {
+            for flag in &modes {
+                errors.push(format!("{flag} can't be used with --clue"));
+            }
+            let filter = WordFilter::default().with_clues(&clues.clues);
+            if clues.len != config.mode.word_len() {
+                errors.push(format!(
+                    "--clue patterns must be {} letters long",
+                    config.mode.word_len()
+                ));
+            } else if !config.secret_words().iter().any(|w| filter.matches(w)) {
+                errors.push("no word fits the --clue patterns".to_owned());
+            }
+        }
+        if let Some(command) = &config.command {
+            let command = command.name();
+            for flag in modes {
+                errors.push(format!("{flag} can't be used with the {command} command"));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+    pub fn new_with_config(config: Config) -> io::Result<Self> {
+        Self::validate_config(&config)
+            .map_err(|errors| io::Error::new(io::ErrorKind::InvalidInput, errors.join("\n")))?;
+        let term = if config.benchmark_fps.is_some() {
+            Terminal::headless(HEADLESS_SIZE)
+        } else {
+            Terminal::new()?
+        };
+        Self::with_terminal(config, term)
+    }
+
+    /// A game drawing to `term` instead of the real terminal, such as one
+    /// made with [`Terminal::with_fixed_size`].
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use yawc::{config::Config, game::Game, raw::Terminal};
+    ///
+    /// // the smallest screen the game fits on
+    /// let term = Terminal::with_fixed_size(47, 13, Box::new(io::sink()));
+    /// let game = Game::with_terminal(Config::default(), term)?;
+    /// game.benchmark_redraws(1)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+    pub fn with_terminal(config: Config, term: Terminal) -> io::Result<Self> {
+        Self::validate_config(&config)
+            .map_err(|errors| io::Error::new(io::ErrorKind::InvalidInput, errors.join("\n")))?;
+        let tournament = config
+            .tournament
+            .then(|| TournamentGame::new(config.seed.unwrap_or_else(|| thread_rng().gen())));
+        let mut generator = generator::for_config(&config);
+        let (width, height) = term.size()?;
+        let color_support = term.capability().color;
+        let mut game = Self {
+            secret_word: match &tournament {
+                Some(t) => t.current_word().unwrap(),
+                None => generator.next_word(),
+            },
             guesses: Vec::default(),
             guess: String::default(),
-            keyboard: Keyboard::default(),
-            term: Terminal::new()?,
-        })
+            candidates: config.secret_words().to_vec(),
+            keyboard: Keyboard::new(
+                config.locale.layout(),
+                config.wide_keys,
+                config.mirror,
+                config.theme,
+            ),
+            tournament,
+            bracket: None,
+            generator,
+            dictionary: Box::new(StaticDictionary::for_config(&config)),
+            logger: config
+                .log_file
+                .as_deref()
+                .map(GameLogger::new)
+                .transpose()?,
+            term,
+            terminal_info: None,
+            size: TerminalSize { width, height },
+            pending_resize: false,
+            show_untried: false,
+            focus: config.focus,
+            deferred_events: VecDeque::new(),
+            suggestion: None,
+            pending_suggestion: None,
+            suggestion_cancel: Arc::default(),
+            suggestion_row: 0,
+            clues: Vec::new(),
+            chain: 1,
+            chain_break: None,
+            game_number: 0,
+            last_event: None,
+            session: Vec::new(),
+            session_start: Instant::now(),
+            quit_mid_game: false,
+            countdown: config.per_guess_timer.map(CountdownTimer::new),
+            session_timer: config
+                .max_time_per_session
+                .map(|limit| (Instant::now(), limit)),
+            color_support,
+            guess_info: None,
+            crash_snapshot: Arc::default(),
+            config,
+        }
+        .with_clues();
+        game.paint_rainbow();
+        Ok(game)
+    }
+
+    /// A game of `secret` on a [`Terminal::headless`] one, for playing from
+    /// code with [`Game::inject_guess`] or [`Game::play_guesses`].
+    ///
+    /// ```
+    /// use yawc::{config::Config, game::Game};
+    ///
+    /// let game = Game::headless(Config::default(), "crane")?;
+    /// assert!(game.possible_words().contains(&"crane"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    pub fn headless(config: Config, secret: &'static str) -> io::Result<Self> {
+        let term = Terminal::headless(HEADLESS_SIZE);
+        Ok(Self::with_terminal(config, term)?.with_generator(Box::new(FixedGenerator(secret))))
+    }
+
+    /// Starts the game knowing what `--clue` says: the keyboard shows it,
+    /// only the words fitting it are left, and the secret word is one of
+    /// them.
+    fn with_clues(mut self) -> Self {
+        let Some(clues) = &self.config.clue else {
+            return self;
+        };
+        self.clues.clone_from(&clues.clues);
+        for clue in &self.clues {
+            self.keyboard.mark_letter(clue.letter, clue.kind);
+        }
+        let filter = self.word_filter();
+        self.candidates.retain(|c| filter.matches(c));
+        if !filter.matches(self.secret_word) {
+            if let Some(word) = self.candidates.choose(&mut thread_rng()) {
+                self.secret_word = word;
+            }
+        }
+        self
+    }
+
+    /// Paints the game in the colours of `game_number` with `--rainbow`, and
+    /// in the closest ones to them the terminal can show.
+    fn paint_rainbow(&mut self) {
+        if self.config.rainbow {
+            self.config.theme.correct = next_correct_color(self.game_number);
+            self.config.theme.misplaced = next_misplaced_color(self.game_number);
+            // they were for the theme's own colours
+            self.config.theme.basic = None;
+        }
+        self.config.theme = self.config.theme.for_color_support(self.color_support);
+        self.keyboard.theme = self.config.theme;
+    }
+
+    /// What the guesses, and the clues if any, say about the secret word.
+    fn word_filter(&self) -> WordFilter {
+        WordFilter::from_guesses(&self.guesses).with_clues(&self.clues)
+    }
+
+    /// Redraws the screen `frames` times in a row with three guesses made,
+    /// returning how long it took. Needs a game made with
+    /// [`Config::benchmark_fps`] set, or with a [`Terminal`] of its own, so
+    /// that nothing is actually drawn.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn benchmark_redraws(mut self, frames: u32) -> io::Result<Duration> {
+        self.secret_word = "crane";
+        for guess in ["slate", "react", "trace"] {
+            let matches_ = check_word(self.secret_word, guess);
+            for (c, m) in guess.chars().zip(matches_) {
+                self.keyboard.mark_letter(c, m);
+            }
+            self.guesses
+                .push(GuessEntry::new(guess.to_owned(), matches_.to_vec()));
+        }
+        let filter = WordFilter::from_guesses(&self.guesses);
+        self.candidates.retain(|c| filter.matches(c));
+
+        let start = Instant::now();
+        for _ in 0..frames {
+            self.redraw_screen()?;
+        }
+        Ok(start.elapsed())
     }
 
+    /// Plays until the player quits or [`interrupt_game`] is called.
     #[allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
     pub fn main_loop(mut self) -> io::Result<()> {
+        if let Some(path) = crash_snapshot_path() {
+            install_panic_hook(path, Arc::clone(&self.crash_snapshot));
+        }
+        let result = match self.play() {
+            Err(e)
+                if e.kind() == io::ErrorKind::Interrupted
+                    && INTERRUPT_FLAG.swap(false, Ordering::Relaxed) =>
+            {
+                Ok(())
+            }
+            result => result,
+        };
+        // nothing to save from a panic anymore
+        *self
+            .crash_snapshot
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+        let summary = self
+            .quit_mid_game
+            .then(|| compute_session_summary(&self.session, self.session_start));
+        // leave the alternate screen first, for the summary to stay visible
+        drop(self);
+        if let Some(summary) = summary {
+            println!("{summary}");
+        }
+        result
+    }
+
+    fn play(&mut self) -> io::Result<()> {
+        if let Some(n) = self.config.puzzle_number() {
+            execute!(self.term, terminal::SetTitle(format!("Wordle #{n}")))?;
+        }
+        if self.config.tournament_4 {
+            let Some(names) = self.read_player_names()? else {
+                return Ok(());
+            };
+            self.bracket = Some(TournamentBracket::new(names));
+        }
+        let mut tip = !self.config.no_tips;
         'game: loop {
+            if self.config.puzzles.is_some() {
+                // not keeping the message of the puzzle before
+                let title = self
+                    .generator
+                    .intro_message()
+                    .unwrap_or_else(|| "yawc".to_owned());
+                execute!(self.term, terminal::SetTitle(title))?;
+            }
+            if let Some(name) = self.bracket.as_ref().and_then(|b| b.current_player()) {
+                let message = format!(
+                    "{name}, it's your turn! Everyone else, look away. Press any key to start."
+                );
+                if !self.show_lines(&[], &StatusMessage::Info(message))? {
+                    break 'game;
+                }
+            }
             self.redraw_screen()?;
-            self.write_status_bar(&["Type in a word and press Enter! CTRL-C to quit."])?;
-            'round: loop {
-                loop {
-                    match event::read()? {
-                        event::Event::Key(k) => match k.code {
-                            KeyCode::Char('C' | 'c') if k.modifiers == KeyModifiers::CONTROL => {
-                                break 'game
-                            }
-                            KeyCode::Char(c)
-                                if c.is_ascii_alphabetic()
-                                    && c.is_ascii_lowercase()
-                                    && self.guess.len() < 5 =>
-                            {
-                                self.guess.push(c);
+            if std::mem::take(&mut tip) {
+                self.show_tip()?;
+            }
+            self.write_prompt()?;
+            let mut deadline = self.guess_deadline();
+            // a resumed game is past its opening
+            let mut opener = (self.guesses.is_empty() && self.guess.is_empty())
+                .then(|| self.config.first_guess.clone())
+                .flatten();
+            let state = 'round: loop {
+                // tournaments and brackets can't be resumed
+                if self.tournament.is_none() && self.bracket.is_none() {
+                    let snapshot = self.snapshot();
+                    *self
+                        .crash_snapshot
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner) = Some(snapshot);
+                }
+                if INTERRUPT_FLAG.swap(false, Ordering::Relaxed) {
+                    self.quit_mid_game = true;
+                    break 'game;
+                }
+                if self.session_time_left().is_some_and(|left| left.is_zero()) {
+                    let message = if self.save_snapshot()? {
+                        "Time's up! The game is saved, yawc resume picks it up again."
+                    } else {
+                        "Time's up!"
+                    };
+                    self.force_status_bar(&StatusMessage::Error(message.to_owned()))?;
+                    thread::sleep(Duration::from_secs(3));
+                    break 'game;
+                }
+                if let Some(word) = opener.take() {
+                    if let Some(state) = self.submit_opener(&word)? {
+                        break 'round state;
+                    }
+                    deadline = self.guess_deadline();
+                    self.redraw_screen()?;
+                    self.write_prompt()?;
+                    continue;
+                }
+                let Some(event) = self.next_event_before(deadline)? else {
+                    if self.session_time_left().is_some_and(|left| left.is_zero()) {
+                        continue;
+                    }
+                    // out of time, the guess goes in as it is
+                    self.complete_guess();
+                    deadline = self.guess_deadline();
+                    if let Some(state) = self.guess()? {
+                        break 'round state;
+                    }
+                    self.redraw_screen()?;
+                    self.write_prompt()?;
+                    continue;
+                };
+                if let event::Event::Key(k) = event {
+                    self.log(&GameEvent::KeyPressed(k));
+                }
+                let previous_event = self.last_event.replace(format!("{event:?}"));
+                match event {
+                    event::Event::Key(k) => match k.code {
+                        KeyCode::Char('D' | 'd')
+                            if k.modifiers == KeyModifiers::CONTROL
+                                && (cfg!(debug_assertions) || self.config.debug) =>
+                        {
+                            self.show_debug_panel(previous_event.as_deref())?;
+                        }
+                        KeyCode::Char('C' | 'c') if k.modifiers == KeyModifiers::CONTROL => {
+                            self.save_snapshot()?;
+                            self.quit_mid_game = true;
+                            break 'game;
+                        }
+                        KeyCode::Char('S' | 's') if k.modifiers == KeyModifiers::CONTROL => {
+                            if let Some(seed) = self.seed() {
+                                write!(self.term, "{}", osc52_copy(&seed.to_string()))?;
+                                self.redraw_screen()?;
+                                self.write_status_bar(&StatusMessage::Info(format!(
+                                    "Copied seed {seed}, play the same words with --seed {seed}."
+                                )))?;
+                                continue;
                             }
-                            KeyCode::Backspace => {
-                                self.guess.pop();
+                        }
+                        KeyCode::Char('Q' | 'q') if k.modifiers == KeyModifiers::CONTROL => {
+                            self.config.quiet = !self.config.quiet;
+                            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+                        }
+                        KeyCode::Char('H' | 'h') if k.modifiers == KeyModifiers::CONTROL => {
+                            self.show_untried = !self.show_untried;
+                            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+                        }
+                        KeyCode::Char('O' | 'o') if k.modifiers == KeyModifiers::CONTROL => {
+                            self.focus = !self.focus;
+                        }
+                        // the usual way of repainting a garbled terminal; the
+                        // screen is drawn again below, like after any key
+                        KeyCode::Char('L' | 'l') if k.modifiers == KeyModifiers::CONTROL => {
+                            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+                        }
+                        KeyCode::Char('F' | 'f')
+                            if k.modifiers == KeyModifiers::CONTROL
+                                && self.config.mode == Mode::Classic =>
+                        {
+                            self.show_frequency_grid()?;
+                        }
+                        KeyCode::Char('G') if self.config.mode == Mode::Classic => {
+                            self.show_word_graph()?;
+                        }
+                        // only in casual games, there's no giving up on the
+                        // daily word or against other players
+                        KeyCode::Char('R' | 'r')
+                            if k.modifiers == KeyModifiers::CONTROL
+                                && !self.config.daily
+                                && self.tournament.is_none()
+                                && self.bracket.is_none() =>
+                        {
+                            self.log(&GameEvent::WordRevealed(self.secret_word.to_owned()));
+                            self.write_status_bar(&StatusMessage::Error(format!(
+                                "The word is {}.",
+                                self.secret_word
+                            )))?;
+                            thread::sleep(Duration::from_secs(5));
+                            break 'round GameState::RevealedLoss;
+                        }
+                        KeyCode::Char(c)
+                            if c.is_alphabetic()
+                                && c.is_lowercase()
+                                && self.guess.chars().count() < self.word_len() =>
+                        {
+                            self.guess.push(c);
+                        }
+                        KeyCode::Backspace => {
+                            self.guess.pop();
+                        }
+                        KeyCode::Tab
+                            if (2..self.word_len()).contains(&self.guess.chars().count()) =>
+                        {
+                            let message = match best_completion(&self.guess, &self.candidates) {
+                                Some(word) => {
+                                    self.guess = word;
+                                    format!(
+                                        "Auto-completed to {} — press Enter to submit or Backspace to edit.",
+                                        self.guess.to_uppercase()
+                                    )
+                                }
+                                None => format!(
+                                    "No remaining word starts with {}.",
+                                    self.guess.to_uppercase()
+                                ),
+                            };
+                            self.redraw_screen()?;
+                            self.write_status_bar(&StatusMessage::Info(message))?;
+                            continue;
+                        }
+                        KeyCode::Enter if self.guess.chars().count() == self.word_len() => {
+                            let guessed = self.guesses.len();
+                            let state = self.guess()?;
+                            // a rejected word doesn't buy more time
+                            if self.guesses.len() > guessed {
+                                deadline = self.guess_deadline();
                             }
-                            KeyCode::Enter if self.guess.len() == 5 => {
-                                if let Some(GameState::Win | GameState::Loose) = self.guess()? {
-                                    break 'round;
+                            if let Some(state) = state {
+                                if matches!(state, GameState::Win)
+                                    && self.guesses.len() == 1
+                                    && !self.config.no_animation
+                                {
+                                    let (width, height) = self.terminal_info().size()?;
+                                    confetti_animation(&mut self.term, width, height)?;
                                 }
+                                break 'round state;
                             }
-                            _ => {}
-                        },
-                        event::Event::Resize(..) => {
-                            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
                         }
-                        event::Event::Mouse(_) => {}
-                    }
-
-                    self.redraw_screen()?;
-                    self.write_status_bar(&["Type in a word and press Enter! CTRL-C to quit."])?;
+                        _ => {}
+                    },
+                    event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                    event::Event::Mouse(_) => {}
                 }
-            }
 
-            match self.final_prompt()? {
+                self.redraw_screen()?;
+                self.write_prompt()?;
+            };
+
+            self.play_sound(&state)?;
+            self.session.push(self.record());
+            // a bracket is one game, played 4 times
+            if self.bracket.is_none() {
+                self.save_score(&state)?;
+            }
+            #[cfg(feature = "svg")]
+            self.export_svg()?;
+            let next = if let Some(t) = &mut self.tournament {
+                t.record(matches!(state, GameState::Win).then_some(self.guesses.len()));
+                self.tournament_prompt(&state)?
+            } else if let Some(b) = &mut self.bracket {
+                b.record(self.guesses.clone());
+                self.bracket_prompt(&state)?
+            } else {
+                self.final_prompt(&state)?
+            };
+            match next {
                 Some(()) => self.start_new_round()?,
                 None => break 'game,
             }
@@ -89,282 +716,3468 @@ impl Game<'_> {
         Ok(())
     }
 
-    fn guess(&mut self) -> io::Result<Option<GameState>> {
-        let (width, _) = size()?;
-        let pos = (
-            width / 2 - 11,
-            (self.guesses.len() * 2 + 1).try_into().unwrap(),
-        );
-        execute!(self.term, cursor::MoveTo(pos.0, pos.1))?;
-        if is_valid_word(&self.guess) {
-            let matches_ = check_word(self.secret_word, &self.guess);
-
-            self.mark_letters(Some(matches_))?;
-
+    /// Shows a sample game in each of the built-in themes for two seconds.
+    /// Any key skips to the next theme, `Esc` or `q` quits.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn theme_gallery(mut self) -> io::Result<()> {
+        self.secret_word = "crane";
+        for guess in ["slate", "react", "crane"] {
+            let matches_ = check_word(self.secret_word, guess);
+            for (c, m) in guess.chars().zip(matches_) {
+                self.keyboard.mark_letter(c, m);
+            }
             self.guesses
-                .push((std::mem::take(&mut self.guess), matches_));
+                .push(GuessEntry::new(guess.to_owned(), matches_.to_vec()));
+        }
+        let filter = WordFilter::from_guesses(&self.guesses);
+        self.candidates.retain(|c| filter.matches(c));
 
-            if self.is_win() {
-                Ok(Some(GameState::Win))
-            } else if self.is_lose() {
-                Ok(Some(GameState::Loose))
-            } else {
-                Ok(None)
+        for (i, theme) in THEMES.iter().enumerate() {
+            self.config.theme = theme.for_color_support(self.color_support);
+            self.keyboard.theme = self.config.theme;
+            execute!(
+                self.term,
+                terminal::SetTitle(format!("yawc: {theme} theme")),
+                terminal::Clear(terminal::ClearType::All)
+            )?;
+            self.redraw_screen()?;
+            self.write_status_bar(&StatusMessage::Info(format!(
+                "Theme {}/{}: {theme}. Any key for the next one, q to quit.",
+                i + 1,
+                THEMES.len()
+            )))?;
+            if self.term.poll_event(Duration::from_secs(2))? {
+                if let event::Event::Key(k) = self.term.read_event()? {
+                    if matches!(k.code, KeyCode::Esc | KeyCode::Char('q')) {
+                        break;
+                    }
+                }
             }
-        } else {
-            self.mark_letters(None)?;
-            Ok(None)
         }
+
+        Ok(())
     }
 
-    fn mark_letters(&mut self, matches_: Option<[Match; 5]>) -> io::Result<()> {
-        if let Some(matches_) = matches_ {
-            for (m, c) in matches_.iter().zip(self.guess.chars()) {
-                write!(
-                    self.term,
-                    " {} │",
-                    match m {
-                        Match::Correct => c.to_ascii_uppercase().black().on_green(),
-                        Match::Misplaced => c.to_ascii_uppercase().black().on_yellow(),
-                        Match::Incorrect => c.to_ascii_uppercase().white(),
-                    }
-                )?;
-                self.keyboard.mark_letter(c, *m);
-            }
-            self.term.flush()?;
-        } else {
-            let (width, _) = size()?;
-            let pos = (
-                width / 2 - 11,
-                (self.guesses.len() * 2 + 1).try_into().unwrap(),
-            );
-            self.write_status_bar(&["Word is not in the world list!"])?;
-            for i in 0..=3 {
-                for c in self.guess.chars() {
-                    if i % 2 == 0 {
-                        write!(self.term, " {} │", c.to_ascii_uppercase().black().on_red())?;
-                    } else {
-                        write!(self.term, " {} │", c.to_ascii_uppercase().red().on_black())?;
+    /// Plays back `games` for `--replay`, one guess a second, as fast or as
+    /// slow as the [`ReplayController`] says.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn replay(mut self, games: &[GuessHistory]) -> io::Result<()> {
+        let mut controller = ReplayController::default();
+        let mut last_tick = Instant::now();
+        for (i, game) in games.iter().enumerate() {
+            self.guesses.clear();
+            self.keyboard.reset();
+            self.candidates = self.config.secret_words().to_vec();
+            execute!(
+                self.term,
+                terminal::SetTitle(format!("yawc: replay {}/{}", i + 1, games.len())),
+                terminal::Clear(terminal::ClearType::All)
+            )?;
+            self.redraw_screen()?;
+            // the empty grid counts as a step too, so that games don't run
+            // into each other
+            let mut guesses = game.iter();
+            loop {
+                self.draw_replay_speed(&controller)?;
+                if self.term.poll_event(REPLAY_TICK)? {
+                    if let event::Event::Key(k) = self.term.read_event()? {
+                        if !controller.handle_key(k.code, Instant::now()) {
+                            return Ok(());
+                        }
                     }
                 }
-                self.term.flush()?;
-                execute!(self.term, cursor::MoveTo(pos.0, pos.1))?;
-                thread::sleep(Duration::from_millis(150));
+                let now = Instant::now();
+                let due = controller.advance(now - last_tick, now);
+                last_tick = now;
+                if !due {
+                    continue;
+                }
+                let Some(guess) = guesses.next() else {
+                    break;
+                };
+                for (c, &m) in guess.word.chars().zip(&guess.matches) {
+                    self.keyboard.mark_letter(c, m);
+                }
+                self.guesses.push(guess.clone());
+                let filter = WordFilter::from_guesses(&self.guesses);
+                self.candidates.retain(|c| filter.matches(c));
+                self.redraw_screen()?;
+            }
+        }
+        self.force_status_bar(&StatusMessage::Info(
+            "End of the replay, press any key.".to_owned(),
+        ))?;
+        loop {
+            if let event::Event::Key(_) = self.term.read_event()? {
+                return Ok(());
             }
         }
-        Ok(())
     }
 
-    fn start_new_round(&mut self) -> io::Result<()> {
-        self.guess.clear();
-        self.guesses.clear();
-        self.secret_word = WORDS.choose(&mut thread_rng()).unwrap();
-        execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
-        self.keyboard = Keyboard::default();
-        self.draw_grid()?;
-        self.write_status_bar(&["Type in a word and press Enter! CTRL-C to quit."])?;
-        Ok(())
+    /// Draws the speed of the replay in the bottom right corner.
+    fn draw_replay_speed(&mut self, controller: &ReplayController) -> io::Result<()> {
+        let (width, height) = self.terminal_info().size()?;
+        let label = format!("{:>8}", controller.label());
+        let len = u16::try_from(label.chars().count()).unwrap_or(width);
+        execute!(
+            self.term,
+            cursor::MoveTo(width.saturating_sub(len), height.saturating_sub(1)),
+            style::Print(label.dark_grey())
+        )
     }
 
-    fn final_prompt(&mut self) -> io::Result<Option<()>> {
-        let (state, word) = if self.is_win() {
-            ("won", self.secret_word.green())
-        } else {
-            ("lose", self.secret_word.red())
-        };
+    /// The on-screen keyboard with the letters marked by the guesses so far.
+    #[must_use]
+    pub fn keyboard(&self) -> &Keyboard {
+        &self.keyboard
+    }
 
+    /// Letters that weren't used in any guess yet, in alphabetical order.
+    #[must_use]
+    pub fn hint_remaining_letters(&self) -> Vec<char> {
+        self.keyboard.untried_letters()
+    }
+
+    /// Asks `question` in the status bar over the empty grid, until it's
+    /// answered with `y` or `n`.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn confirm(&mut self, question: &str) -> io::Result<bool> {
         loop {
             self.redraw_screen()?;
-            self.write_status_bar(&[
-                "You ",
-                state,
-                "! The word was ",
-                &word.to_string(),
-                ". Start again? y/n ",
-            ])?;
-            match event::read()? {
+            self.force_status_bar(&StatusMessage::Info(question.to_owned()))?;
+            match self.next_event()? {
                 event::Event::Key(k) => match k.code {
-                    KeyCode::Char('y') => return Ok(Some(())),
-                    KeyCode::Char('n') => return Ok(None),
+                    KeyCode::Char('y') => return Ok(true),
+                    KeyCode::Char('n') => return Ok(false),
                     _ => {}
                 },
-                event::Event::Resize(..) => {
-                    execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
-                }
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
                 event::Event::Mouse(_) => (),
             }
         }
     }
 
-    fn write_status_bar(&mut self, strings: &[&str]) -> io::Result<()> {
-        let (width, height) = size()?;
-        match height {
-            0..=13 => Ok(()),
-            14.. => {
-                let height = if height > 14 { height - 2 } else { height };
-                let len: u16 = strings
-                    .iter()
-                    .map(|s| {
-                        use strip_ansi_escapes::strip;
-                        strip(s).unwrap().iter().count()
-                    })
-                    .sum::<usize>()
-                    .try_into()
-                    .unwrap();
-                execute!(
-                    self.term,
-                    cursor::SavePosition,
-                    cursor::MoveTo(width / 2 - len / 2u16, height),
-                    terminal::Clear(terminal::ClearType::CurrentLine),
-                )?;
-                for string in strings {
-                    write!(self.term, "{}", string)?;
-                }
-                execute!(self.term, cursor::RestorePosition)?;
-                Ok(())
+    /// Picks up the game in `snapshot` where it was left. Snapshots of games
+    /// that can't be played with the current options, like with a word of
+    /// another length, are ignored.
+    #[must_use]
+    pub fn resume(mut self, snapshot: &Snapshot) -> Self {
+        let Some(&word) = self
+            .config
+            .secret_words()
+            .iter()
+            .find(|&&w| w == snapshot.word)
+        else {
+            return self;
+        };
+        if self.tournament.is_some()
+            || !snapshot.guesses.iter().all(|g| self.dictionary.is_valid(g))
+        {
+            return self;
+        }
+
+        self.secret_word = word;
+        for guess in &snapshot.guesses {
+            let matches_ = self.check(guess);
+            for (c, m) in guess.chars().zip(matches_.iter().copied()) {
+                self.keyboard.mark_letter(c, m);
             }
+            self.guesses.push(GuessEntry::new(guess.clone(), matches_));
         }
+        let filter = self.word_filter();
+        self.candidates.retain(|c| filter.matches(c));
+        self.start_suggestion();
+        self.guess = snapshot
+            .current_guess
+            .chars()
+            .take(self.word_len())
+            .collect();
+        self
     }
 
-    fn display_input(&mut self) -> io::Result<()> {
-        let (width, _) = size()?;
-        execute!(
-            self.term,
-            cursor::MoveTo(width / 2 - 11, 1),
-            cursor::SavePosition,
-        )?;
-        for (w, matches_) in &self.guesses {
-            for (c, l) in w.chars().zip(matches_) {
-                write!(
-                    self.term,
-                    " {} │",
-                    match l {
-                        Match::Correct => c.to_ascii_uppercase().black().on_green(),
-                        Match::Misplaced => c.to_ascii_uppercase().black().on_yellow(),
-                        Match::Incorrect => c.to_ascii_uppercase().white(),
-                    }
-                )?;
-            }
-            execute!(
-                self.term,
-                cursor::RestorePosition,
-                cursor::MoveDown(2),
-                cursor::SavePosition,
-            )?;
+    /// Goes by `info` for the size of the screen rather than by the terminal
+    /// drawn to, like to try out how the game fits on a smaller screen.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn with_terminal_info(mut self, info: Box<dyn TerminalInfo>) -> io::Result<Self> {
+        let (width, height) = info.size()?;
+        self.size = TerminalSize { width, height };
+        self.terminal_info = Some(info);
+        Ok(self)
+    }
+
+    /// What the size of the screen is asked of.
+    fn terminal_info(&self) -> &dyn TerminalInfo {
+        match &self.terminal_info {
+            Some(info) => info.as_ref(),
+            None => &self.term,
         }
-        for c in self.guess.chars() {
-            write!(self.term, " {} │", c.to_ascii_uppercase().white())?;
-            self.term.flush()?;
+    }
+
+    /// Replaces the source of secret words, starting over with a word from
+    /// `generator`. Tournaments keep their own words.
+    #[must_use]
+    pub fn with_generator(mut self, mut generator: Box<dyn SecretWordGenerator>) -> Self {
+        if self.tournament.is_none() {
+            self.secret_word = generator.next_word();
         }
-        Ok(())
+        self.generator = generator;
+        self
     }
 
-    fn is_win(&self) -> bool {
-        self.guesses.last().unwrap().1 == [Match::Correct; 5] && self.guesses.len() <= 6
+    /// Replaces what decides which guesses are words, like to accept made up
+    /// ones:
+    ///
+    /// ```
+    /// use yawc::{config::Config, dictionary::DictionaryBackend, game::Game, stats::Snapshot};
+    /// # use std::io;
+    ///
+    /// struct MockDictionary;
+    ///
+    /// impl DictionaryBackend for MockDictionary {
+    ///     fn is_valid(&self, word: &str) -> bool {
+    ///         word.len() == 5
+    ///     }
+    /// }
+    ///
+    /// let snapshot = Snapshot {
+    ///     word: "crane".to_owned(),
+    ///     guesses: vec!["zzzzz".to_owned()],
+    ///     current_guess: "c".to_owned(),
+    /// };
+    ///
+    /// // "zzzzz" isn't a word, so the snapshot can't be resumed
+    /// let game = Game::headless(Config::default(), "crane")?.resume(&snapshot);
+    /// assert!(game.possible_words().iter().any(|w| w.contains('z')));
+    ///
+    /// let game = Game::headless(Config::default(), "crane")?
+    ///     .with_dictionary(Box::new(MockDictionary))
+    ///     .resume(&snapshot);
+    /// assert!(!game.possible_words().iter().any(|w| w.contains('z')));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: Box<dyn DictionaryBackend>) -> Self {
+        self.dictionary = dictionary;
+        self
     }
 
-    fn is_lose(&self) -> bool {
-        self.guesses.last().unwrap().1 != [Match::Correct; 5] && self.guesses.len() >= 6
+    /// Secret words that are still possible given the guesses made so far.
+    #[must_use]
+    pub fn possible_words(&self) -> &[&'static str] {
+        &self.candidates
     }
 
-    fn draw_grid(&mut self) -> io::Result<()> {
-        let (width, height) = size()?;
+    fn guess(&mut self) -> io::Result<Option<GameState>> {
+        let (width, _) = self.terminal_info().size()?;
+        let pos = (
+            width / 2 - self.grid_offset(),
+            self.grid_top() + u16::try_from(self.guesses.len() * 2 + 1).unwrap(),
+        );
+        execute!(self.term, cursor::MoveTo(pos.0, pos.1))?;
+        if self.guess == EASTER_EGG_WORD {
+            if !self.config.no_animation {
+                let (width, height) = self.terminal_info().size()?;
+                easter_egg_animation(&mut self.term, width, height)?;
+            }
+            // it doesn't count as a guess
+            self.guess.clear();
+            self.redraw_screen()?;
+            Ok(None)
+        } else if !self.dictionary.is_valid(&self.guess) {
+            self.reject_guess("Word is not in the world list!")?;
+            Ok(None)
+        } else if let Some(error) = self.rule_violation(&self.guess) {
+            self.reject_guess(&error)?;
+            Ok(None)
+        } else {
+            let matches_ = self.check(&self.guess);
 
-        execute!(self.term, cursor::MoveTo(width / 2 - 12, 0))?;
-        execute!(
-            self.term,
-            cursor::SavePosition,
-            style::Print("┌───┬───┬───┬───┬───┐"),
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-        )?;
-        for _ in 0..5 {
-            execute!(
-                self.term,
-                cursor::SavePosition,
-                style::Print("│   │   │   │   │   │"),
-                cursor::RestorePosition,
-                cursor::MoveDown(1),
-                cursor::SavePosition,
-                style::Print("├───┼───┼───┼───┼───┤"),
-                cursor::RestorePosition,
-                cursor::MoveDown(1),
-            )?;
+            self.mark_letters(&matches_)?;
+            self.submit_guess(matches_)?;
+            if !self.config.no_animation {
+                self.show_match_diff()?;
+            }
+            if self.config.word_relationship {
+                self.show_word_relationship()?;
+            }
+            Ok(self.game_state())
         }
-        execute!(
-            self.term,
-            cursor::SavePosition,
-            style::Print("│   │   │   │   │   │"),
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-            cursor::SavePosition,
-            style::Print("└───┴───┴───┴───┴───┘"),
-            cursor::RestorePosition,
-        )?;
-        if height > 13 + 7 + 1 && width >= 48 {
-            let height = if height >= 13 + 1 + 12 {
-                height - 12
-            } else {
-                13
-            };
-            execute!(self.term, cursor::MoveTo(width / 2 - 20, height))?;
-            write!(self.term, "{}", self.keyboard)?;
+    }
+
+    /// Makes `word` the next guess without going through the screen or the
+    /// keyboard, like for playing a game from code. Works just as well with
+    /// a [`Terminal::headless`] one.
+    ///
+    /// ```
+    /// use yawc::{config::Config, game::{Game, GameState}};
+    ///
+    /// let mut game = Game::headless(Config::default(), "crane")?;
+    /// assert_eq!(game.inject_guess("slate")?, None);
+    /// assert_eq!(game.inject_guess("crane")?, Some(GameState::Win));
+    /// # Ok::<_, yawc::game::GameError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the game is over, or `word` can't be guessed, like when it's not
+    /// in the word list, see [`GameError`].
+    pub fn inject_guess(&mut self, word: &str) -> Result<Option<GameState>, GameError> {
+        if self.game_state().is_some() {
+            return Err(GameError::GameOver);
         }
-        Ok(())
+        if word.chars().count() != self.word_len() || !self.dictionary.is_valid(word) {
+            return Err(GameError::InvalidWord(word.to_owned()));
+        }
+        if let Some(error) = self.rule_violation(word) {
+            return Err(GameError::RuleViolation(error));
+        }
+        self.guess = word.to_owned();
+        let matches_ = self.check(word);
+        for (c, m) in word.chars().zip(matches_.iter().copied()) {
+            self.keyboard.mark_letter(c, m);
+        }
+        self.submit_guess(matches_)?;
+        Ok(self.game_state())
     }
 
-    fn redraw_screen(&mut self) -> io::Result<()> {
-        let (width, height) = size()?;
-        execute!(
-            self.term,
-            terminal::SetSize(cmp::max(width, 47), cmp::max(height, 13))
+    /// Why `word` can't be the next guess with `--hard`, `--superhard` or
+    /// `--crossword`, if it can't.
+    fn rule_violation(&self, word: &str) -> Option<String> {
+        if let Some(error) = self
+            .config
+            .hard
+            .then(|| hard_mode_check(&self.guesses, word))
+            .flatten()
+        {
+            return Some(error);
+        }
+        if let Some(error) = self
+            .config
+            .superhard
+            .then(|| superhard_mode_check(&self.guesses, word))
+            .flatten()
+        {
+            return Some(error);
+        }
+        let crossword = self.config.crossword.as_ref()?;
+        let guesses: Vec<GuessHistory> = self.session.iter().map(|r| r.guesses.clone()).collect();
+        crossword.violation(&guesses, self.session.len(), word)
+    }
+
+    /// Plays `guesses` one after the other with [`Game::inject_guess`], and
+    /// tells how the game went.
+    ///
+    /// ```
+    /// use yawc::{
+    ///     config::Config,
+    ///     game::{Game, GameError, GameResult, GameState},
+    /// };
+    ///
+    /// let game = Game::headless(Config::default(), "crane")?;
+    /// assert_eq!(
+    ///     game.play_guesses(&["slate", "pious", "crane"])?,
+    ///     GameResult { state: Some(GameState::Win), guesses_used: 3 },
+    /// );
+    /// # Ok::<_, GameError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If one of the guesses can't be made, see [`Game::inject_guess`].
+    pub fn play_guesses(mut self, guesses: &[&str]) -> Result<GameResult, GameError> {
+        for guess in guesses {
+            self.inject_guess(guess)?;
+        }
+        Ok(GameResult {
+            state: self.game_state(),
+            guesses_used: self.guesses.len(),
+        })
+    }
+
+    /// Adds the current guess, which got `matches_`, to the guesses.
+    fn submit_guess(&mut self, matches_: Vec<Match>) -> io::Result<()> {
+        self.log(&GameEvent::GuessSubmitted {
+            word: self.guess.clone(),
+            matches: matches_.clone(),
+        });
+        self.guesses
+            .push(GuessEntry::new(std::mem::take(&mut self.guess), matches_));
+        self.filter_candidates()?;
+        self.start_suggestion();
+        Ok(())
+    }
+
+    /// How the game ended, if it did.
+    fn game_state(&self) -> Option<GameState> {
+        if self.guesses.is_empty() {
+            None
+        } else if self.is_win() {
+            Some(GameState::Win)
+        } else if self.is_lose() {
+            Some(GameState::Loose)
+        } else {
+            None
+        }
+    }
+
+    /// Matches of `guess` against the secret word.
+    fn check(&self, guess: &str) -> Vec<Match> {
+        match self.config.mode {
+            Mode::Classic => check_word(self.secret_word, guess).to_vec(),
+            Mode::Plus => check_letters::<7>(self.secret_word, guess).to_vec(),
+        }
+    }
+
+    fn mark_letters(&mut self, matches_: &[Match]) -> io::Result<()> {
+        for (m, c) in matches_.iter().zip(self.guess.chars()) {
+            write!(self.term, "{}", tile_cell(&self.config, c, *m))?;
+            self.keyboard.mark_letter(c, *m);
+        }
+        self.term.flush()?;
+        Ok(())
+    }
+
+    /// Letter the next secret word starts with in `--wordchain`.
+    fn chain_letter(&self) -> Option<char> {
+        self.secret_word.chars().last()
+    }
+
+    /// A secret word starting with [`chain_letter`](Self::chain_letter), or
+    /// any word if there's none, breaking the chain.
+    fn next_chained_word(&mut self) -> &'static str {
+        let letter = self.chain_letter();
+        let words: Vec<_> = self
+            .config
+            .secret_words()
+            .iter()
+            .filter(|w| w.chars().next() == letter)
+            .collect();
+        match words.choose(&mut thread_rng()) {
+            Some(word) => {
+                self.chain += 1;
+                self.chain_break = None;
+                word
+            }
+            None => {
+                self.chain = 1;
+                self.chain_break = letter;
+                self.generator.next_word()
+            }
+        }
+    }
+
+    /// What `--wordchain` says about the current word, ahead of the prompt.
+    fn chain_status(&self) -> String {
+        if !self.config.wordchain {
+            return String::new();
+        }
+        match (self.chain_break, self.secret_word.chars().next()) {
+            (Some(letter), _) => format!(
+                "Chain break, no word starts with {}! ",
+                uppercase_letter(letter)
+            ),
+            (None, Some(first)) if self.chain > 1 => format!(
+                "Chain of {}, the word starts with {}. ",
+                self.chain,
+                uppercase_letter(first)
+            ),
+            _ => String::new(),
+        }
+    }
+
+    /// Shows a random tip for three seconds, or until a key is pressed, then
+    /// fades it out a letter at a time.
+    fn show_tip(&mut self) -> io::Result<()> {
+        let tip = random_tip(&mut thread_rng());
+        self.write_status_bar(&StatusMessage::Info(tip.to_owned()))?;
+        for _ in 0..30 {
+            self.poll_resize()?;
+            if self.pending_resize || !self.deferred_events.is_empty() {
+                return self.redraw_after_resize();
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        let mut faded: Vec<_> = tip.chars().collect();
+        for i in 0..faded.len() {
+            faded[i] = ' ';
+            let text = faded.iter().collect();
+            self.write_status_bar(&StatusMessage::Info(text))?;
+            thread::sleep(Duration::from_millis(15));
+        }
+        Ok(())
+    }
+
+    /// Types in `word` a letter at a time and submits it, for `--first-guess`.
+    fn submit_opener(&mut self, word: &str) -> io::Result<Option<GameState>> {
+        if self.config.no_animation {
+            self.guess = word.to_owned();
+            return self.guess();
+        }
+        for c in word.chars() {
+            self.guess.push(c);
+            self.display_input()?;
+            thread::sleep(Duration::from_millis(100));
+            self.poll_resize()?;
+            self.redraw_after_resize()?;
+        }
+        self.guess()
+    }
+
+    /// Flashes the current guess in red, showing why it can't be submitted.
+    fn reject_guess(&mut self, message: &str) -> io::Result<()> {
+        let (width, _) = self.terminal_info().size()?;
+        let pos = (
+            width / 2 - self.grid_offset(),
+            self.grid_top() + u16::try_from(self.guesses.len() * 2 + 1).unwrap(),
+        );
+        self.write_status_bar(&StatusMessage::Error(message.to_owned()))?;
+        for i in 0..=3 {
+            self.poll_resize()?;
+            if self.pending_resize {
+                break;
+            }
+            for c in self.guess.chars() {
+                if i % 2 == 0 {
+                    write!(self.term, " {} │", uppercase_letter(c).black().on_red())?;
+                } else {
+                    write!(self.term, " {} │", uppercase_letter(c).red().on_black())?;
+                }
+            }
+            self.term.flush()?;
+            execute!(self.term, cursor::MoveTo(pos.0, pos.1))?;
+            thread::sleep(Duration::from_millis(150));
+        }
+        self.redraw_after_resize()
+    }
+
+    /// Marks for a second the letters of the last guess that matched better,
+    /// or worse, than the same letters of the guess before, with arrows on
+    /// the border under them.
+    fn show_match_diff(&mut self) -> io::Result<()> {
+        let [.., prev, curr] = self.guesses.as_slice() else {
+            return Ok(());
+        };
+        let (Ok(prev), Ok(curr)) = (
+            <[Match; 5]>::try_from(prev.matches.as_slice()),
+            <[Match; 5]>::try_from(curr.matches.as_slice()),
+        ) else {
+            return Ok(());
+        };
+        let diff = match_diff(prev, curr);
+        if diff.iter().all(Option::is_none) {
+            return Ok(());
+        }
+
+        let (width, _) = self.terminal_info().size()?;
+        let x = width / 2 - self.grid_offset() + 1;
+        let y = self.grid_top() + u16::try_from(self.guesses.len() * 2).unwrap();
+        for (i, diff) in (0..).zip(diff) {
+            let arrow = match diff {
+                Some(true) => '▲'.green(),
+                Some(false) => '▼'.red(),
+                None => continue,
+            };
+            execute!(
+                self.term,
+                cursor::SavePosition,
+                cursor::MoveTo(x + i * 4, y),
+                style::PrintStyledContent(arrow),
+                cursor::RestorePosition,
+            )?;
+        }
+        for _ in 0..10 {
+            self.poll_resize()?;
+            if self.pending_resize {
+                break;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        self.redraw_after_resize()
+    }
+
+    /// Shows for two seconds which letters the last two guesses share, and
+    /// which are only in one of them.
+    fn show_word_relationship(&mut self) -> io::Result<()> {
+        let [.., prev, curr] = self.guesses.as_slice() else {
+            return Ok(());
+        };
+        let (only_prev, shared, only_curr) = letter_sets_diff(&prev.word, &curr.word);
+        let list = |letters: Vec<char>| {
+            let letters: Vec<_> = letters
+                .into_iter()
+                .map(|c| uppercase_letter(c).to_string())
+                .collect();
+            if letters.is_empty() {
+                "none".to_owned()
+            } else {
+                letters.join(", ")
+            }
+        };
+        let message = format!(
+            "Letters only in {}: {} | Shared: {} | Letters only in {}: {}",
+            prev.word.to_uppercase(),
+            list(only_prev),
+            list(shared),
+            curr.word.to_uppercase(),
+            list(only_curr),
+        );
+        self.write_status_bar(&StatusMessage::Info(message))?;
+        for _ in 0..20 {
+            self.poll_resize()?;
+            if self.pending_resize {
+                break;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        self.redraw_after_resize()
+    }
+
+    fn start_new_round(&mut self) -> io::Result<()> {
+        self.guess.clear();
+        self.guesses.clear();
+        self.candidates.clear();
+        self.candidates
+            .extend_from_slice(self.config.secret_words());
+        self.suggestion = None;
+        self.cancel_suggestion();
+        self.clues.clear();
+        self.game_number += 1;
+        self.paint_rainbow();
+        self.secret_word = match &self.tournament {
+            Some(t) => t.current_word().unwrap(),
+            // everyone in a bracket plays the same word
+            None if self.bracket.is_some() => self.secret_word,
+            None if self.config.wordchain => self.next_chained_word(),
+            None => self.generator.next_word(),
+        };
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+        self.keyboard.reset();
+        self.draw_grid()?;
+        self.write_prompt()?;
+        Ok(())
+    }
+
+    fn play_sound(&mut self, state: &GameState) -> io::Result<()> {
+        if !self.config.no_sound {
+            let played = match state {
+                GameState::Win => sound::play_win_sound(),
+                GameState::Loose | GameState::RevealedLoss => sound::play_lose_sound(),
+            };
+            if let Err(e) = played {
+                // don't try again for the rest of the session
+                self.config.no_sound = true;
+                self.warn("play sound", e)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn save_score(&mut self, state: &GameState) -> io::Result<()> {
+        let record = ScoreRecord {
+            chain: self.config.wordchain.then_some(self.chain),
+            revealed: matches!(state, GameState::RevealedLoss),
+            ..ScoreRecord::now(&self.record(), &self.config)
+        };
+        let saved = stats::append_score(&record).and_then(|()| stats::clear_snapshot());
+        if let Err(e) = saved {
+            self.warn("save the score", e)?;
+        }
+        Ok(())
+    }
+
+    /// Saves the game that just ended as an image with `--export-svg`.
+    #[cfg(feature = "svg")]
+    fn export_svg(&mut self) -> io::Result<()> {
+        let Some(path) = &self.config.export_svg else {
+            return Ok(());
+        };
+        let svg = crate::svg::generate_wordle_image(&self.record(), &self.config.theme);
+        if let Err(e) = std::fs::write(path, svg) {
+            self.warn("save the image", e)?;
+        }
+        Ok(())
+    }
+
+    /// The game as it is now.
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            word: self.secret_word.to_owned(),
+            guesses: self.guesses.iter().map(|g| g.word.clone()).collect(),
+            current_guess: self.guess.clone(),
+        }
+    }
+
+    /// Keeps the game being quit for `yawc resume`, if it can be resumed.
+    /// Whether it was kept.
+    fn save_snapshot(&mut self) -> io::Result<bool> {
+        if self.tournament.is_some() || self.bracket.is_some() {
+            return Ok(false);
+        }
+        let snapshot = self.snapshot();
+        let saved = if snapshot.is_in_progress() {
+            stats::save_snapshot(&snapshot)
+        } else {
+            stats::clear_snapshot()
+        };
+        if let Err(e) = &saved {
+            self.warn("save the game", e)?;
+        }
+        Ok(saved.is_ok() && snapshot.is_in_progress())
+    }
+
+    fn final_prompt(&mut self, state: &GameState) -> io::Result<Option<()>> {
+        let mut quality = format!(
+            "{}. Score: {}",
+            self.quality_summary(),
+            self.record().score(&self.config)
+        );
+        if is_pangram(letters_used(&self.guesses)) {
+            quality.push_str(". Pangram! 🦋");
+        }
+        if let Some(letter) = self.chain_letter().filter(|_| self.config.wordchain) {
+            quality.push_str(&format!(
+                ". Chain: {}. Next word starts with {}",
+                self.chain,
+                uppercase_letter(letter)
+            ));
+        }
+        let word = if supports_hyperlinks() {
+            osc8_link(
+                &format!(
+                    "https://www.merriam-webster.com/dictionary/{}",
+                    self.secret_word
+                ),
+                self.secret_word,
+            )
+        } else {
+            self.secret_word.to_owned()
+        };
+        // the solver only knows the English 5-letter words
+        let analysis = self.config.mode == Mode::Classic
+            && self.config.locale == Locale::En
+            && self.config.custom_words.is_none()
+            && !self.guesses.is_empty();
+        let options = format!(
+            "Start again? y/n{}, h for history{} ",
+            if analysis { ", b for bot analysis" } else { "" },
+            if cfg!(feature = "qr") {
+                ", q for a QR code"
+            } else {
+                ""
+            },
+        );
+        #[cfg(feature = "qr")]
+        if self.config.share_qr {
+            self.show_share_qr()?;
+        }
+        loop {
+            self.redraw_screen()?;
+            match state {
+                GameState::Win => self.force_status_bar(&StatusMessage::Success(format!(
+                    "You won! The word was {word}. {quality}. {options}"
+                )))?,
+                GameState::Loose => self.force_status_bar(&StatusMessage::Error(format!(
+                    "You lose! The word was {word}. {quality}. {options}"
+                )))?,
+                GameState::RevealedLoss => self.force_status_bar(&StatusMessage::Error(
+                    format!("You revealed the word. Better luck next time! {options}"),
+                ))?,
+            }
+            match self.next_event()? {
+                event::Event::Key(k) => match k.code {
+                    KeyCode::Char('y') => return Ok(Some(())),
+                    KeyCode::Char('n') => return Ok(None),
+                    KeyCode::Char('b') if analysis => self.show_bot_analysis()?,
+                    KeyCode::Char('h') => self.show_history()?,
+                    #[cfg(feature = "qr")]
+                    KeyCode::Char('q') => self.show_share_qr()?,
+                    _ => {}
+                },
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+    }
+
+    /// Shows a QR code of the [`share_link`] of the game that just ended,
+    /// until a key is pressed. It takes more than 42 columns, and as many
+    /// rows as the code needs.
+    #[cfg(feature = "qr")]
+    fn show_share_qr(&mut self) -> io::Result<()> {
+        let link = share_link(&self.guesses);
+        loop {
+            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+            let (width, height) = self.terminal_info().size()?;
+            // leaving the last row for the status bar
+            let size = link.as_deref().and_then(crate::qr::drawn_size);
+            match (&link, size) {
+                (Some(link), Some((w, h))) if width > 42 && w <= width && h < height => {
+                    crate::qr::render_qr(link, &mut self.term, ((width - w) / 2, 0))?;
+                    self.force_status_bar(&StatusMessage::Info(
+                        "Scan it to share your game. Press any key to go back.".to_owned(),
+                    ))?;
+                }
+                (None, _) => self.force_status_bar(&StatusMessage::Error(
+                    "Only games of 5 English letters can be shared as a link. Press any key to \
+                     go back."
+                        .to_owned(),
+                ))?,
+                _ => self.force_status_bar(&StatusMessage::Error(
+                    "The terminal is too small for the QR code. Press any key to go back."
+                        .to_owned(),
+                ))?,
+            }
+            match self.next_event()? {
+                event::Event::Key(_) => break,
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))
+    }
+
+    /// Shows a [`HistoryViewer`] of the scores file until it's closed.
+    fn show_history(&mut self) -> io::Result<()> {
+        let mut viewer = HistoryViewer::new(stats::load_scores()?);
+        loop {
+            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+            if self.size.fits(self.min_size()) {
+                let (width, height) = self.terminal_info().size()?;
+                let x = (width / 2).saturating_sub(HistoryViewer::WIDTH / 2);
+                for (row, line) in (2..).zip(viewer.lines(usize::from(height.saturating_sub(5)))) {
+                    execute!(self.term, cursor::MoveTo(x, row), style::Print(line))?;
+                }
+                self.force_status_bar(&StatusMessage::Info(
+                    "↑/↓ PgUp/PgDn to move, Enter for guesses, r to reveal words, Esc to go back."
+                        .to_owned(),
+                ))?;
+            }
+            match self.next_event()? {
+                event::Event::Key(k) => {
+                    if !viewer.handle_key(k.code) {
+                        break;
+                    }
+                }
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))
+    }
+
+    /// Shows [`bot_analysis`] of the game until a key is pressed.
+    fn show_bot_analysis(&mut self) -> io::Result<()> {
+        let lines: Vec<_> = bot_analysis(&self.record())
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let player = format!(
+                    "{}. {}  {:>5.2} bits",
+                    i + 1,
+                    m.player_guess.to_uppercase(),
+                    m.player_info_bits
+                );
+                if m.is_optimal() {
+                    format!("{player}   You played optimally!")
+                } else {
+                    format!(
+                        "{player}   bot: {}  {:>5.2} bits",
+                        m.bot_guess.to_uppercase(),
+                        m.bot_info_bits
+                    )
+                }
+            })
+            .collect();
+        self.show_lines(
+            &lines,
+            &StatusMessage::Info("Press any key to go back.".to_owned()),
+        )?;
+        Ok(())
+    }
+
+    /// Shows `lines` centered on an otherwise empty screen, with `message` in
+    /// the status bar, until a key is pressed. Returns `false` if it was
+    /// `Ctrl+C`.
+    fn show_lines(&mut self, lines: &[String], message: &StatusMessage) -> io::Result<bool> {
+        let quit = loop {
+            let (width, _) = self.terminal_info().size()?;
+            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+            for (row, line) in (2..).zip(lines) {
+                let len = UnicodeWidthStr::width(line.as_str()) as u16;
+                execute!(
+                    self.term,
+                    cursor::MoveTo((width / 2).saturating_sub(len / 2), row),
+                    style::Print(line),
+                )?;
+            }
+            self.force_status_bar(message)?;
+            match self.next_event()? {
+                event::Event::Key(k) => {
+                    break k.code == KeyCode::Char('c') && k.modifiers == KeyModifiers::CONTROL
+                }
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        };
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+        Ok(!quit)
+    }
+
+    /// Asks each of the [`PLAYERS`] of a bracket for their name, [`None`] if
+    /// they quit instead.
+    fn read_player_names(&mut self) -> io::Result<Option<Vec<String>>> {
+        let mut names = Vec::with_capacity(PLAYERS);
+        let mut name = String::new();
+        while names.len() < PLAYERS {
+            let player = names.len() + 1;
+            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+            self.force_status_bar(&StatusMessage::Info(format!(
+                "Player {player}, type in your name and press Enter: {name}"
+            )))?;
+            match self.next_event()? {
+                event::Event::Key(k) => match k.code {
+                    KeyCode::Char('C' | 'c') if k.modifiers == KeyModifiers::CONTROL => {
+                        return Ok(None)
+                    }
+                    KeyCode::Char(c) if !c.is_control() && name.chars().count() < 16 => {
+                        name.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        name.pop();
+                    }
+                    KeyCode::Enter => {
+                        let name = std::mem::take(&mut name);
+                        let name = name.trim();
+                        names.push(if name.is_empty() {
+                            format!("Player {player}")
+                        } else {
+                            name.to_owned()
+                        });
+                    }
+                    _ => {}
+                },
+                event::Event::Resize(width, height) => self.record_resize(width, height),
+                event::Event::Mouse(_) => (),
+            }
+        }
+        Ok(Some(names))
+    }
+
+    /// Hides the finished turn of a bracket player from the next one, or
+    /// shows the ranking once everyone played.
+    fn bracket_prompt(&mut self, state: &GameState) -> io::Result<Option<()>> {
+        let Some(b) = &self.bracket else {
+            return Ok(None);
+        };
+        if b.is_finished() {
+            let lines: Vec<_> = b
+                .ranking()
+                .iter()
+                .enumerate()
+                .map(|(i, p)| match p.guesses() {
+                    // padded to the same width, so that the columns line up
+                    Some(1) => format!("{}. {:<16} 1 guess  ", i + 1, p.name),
+                    Some(n) => format!("{}. {:<16} {n} guesses", i + 1, p.name),
+                    None => format!("{}. {:<16} lost     ", i + 1, p.name),
+                })
+                .collect();
+            let message = StatusMessage::Info(format!(
+                "The word was {}. Press any key to quit.",
+                self.secret_word
+            ));
+            self.show_lines(&lines, &message)?;
+            return Ok(None);
+        }
+
+        let result = match state {
+            GameState::Win if self.guesses.len() == 1 => "You found the word in 1 guess".to_owned(),
+            GameState::Win => format!("You found the word in {} guesses", self.guesses.len()),
+            GameState::Loose | GameState::RevealedLoss => "You didn't find the word".to_owned(),
+        };
+        let next = b.current_player().unwrap_or_default();
+        let message = StatusMessage::Info(format!(
+            "{result}! Pass the keyboard to {next} and press any key."
+        ));
+        Ok(self.show_lines(&[], &message)?.then_some(()))
+    }
+
+    /// Shows a [`LetterFrequencyGrid`] of the candidates over the game until a
+    /// key is pressed.
+    fn show_frequency_grid(&mut self) -> io::Result<()> {
+        let grid = LetterFrequencyGrid::new(&self.candidates);
+        loop {
+            self.redraw_screen()?;
+            if self.size.fits(self.min_size()) {
+                let (width, _) = self.terminal_info().size()?;
+                let x = (width / 2).saturating_sub(LetterFrequencyGrid::WIDTH / 2);
+                let y = self.grid_top() + 3;
+                execute!(self.term, cursor::SavePosition, cursor::MoveTo(x, y))?;
+                write!(self.term, "{grid}")?;
+                execute!(self.term, cursor::RestorePosition)?;
+                self.write_status_bar(&StatusMessage::Info(
+                    "Most common letters at each position. Press any key to go back.".to_owned(),
+                ))?;
+            }
+            match self.next_event()? {
+                event::Event::Key(_) => break,
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))
+    }
+
+    /// Shows a [`WordGraph`] of the remaining candidates until a key is
+    /// pressed.
+    fn show_word_graph(&mut self) -> io::Result<()> {
+        let graph = WordGraph::new(&self.candidates);
+        loop {
+            self.redraw_screen()?;
+            if self.size.fits(self.min_size()) {
+                let (width, _) = self.terminal_info().size()?;
+                let x = (width / 2).saturating_sub(WordGraph::WIDTH / 2);
+                let y = self.grid_top() + 3;
+                execute!(self.term, cursor::SavePosition, cursor::MoveTo(x, y))?;
+                write!(self.term, "{graph}")?;
+                execute!(self.term, cursor::RestorePosition)?;
+                self.write_status_bar(&StatusMessage::Info(
+                    "How often two positions have the same letter. Press any key to go back."
+                        .to_owned(),
+                ))?;
+            }
+            match self.next_event()? {
+                event::Event::Key(_) => break,
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))
+    }
+
+    /// Shows what the game is keeping track of in a box over the grid, until
+    /// any key is pressed. `last_event` is the one before `Ctrl+D` opened it.
+    fn show_debug_panel(&mut self, last_event: Option<&str>) -> io::Result<()> {
+        let lines = [
+            format!("Secret word:       {}", self.secret_word),
+            format!(
+                "Guesses remaining: {}",
+                MAX_GUESSES.saturating_sub(self.guesses.len())
+            ),
+            format!("Candidates:        {}", self.candidates.len()),
+            format!(
+                "Seed:              {}",
+                self.config
+                    .seed
+                    .map_or_else(|| "none".to_owned(), |s| s.to_string())
+            ),
+            format!(
+                "Heap allocations:  {}",
+                allocations().map_or_else(|| "not tracked".to_owned(), |n| n.to_string())
+            ),
+            format!("Last event:        {}", last_event.unwrap_or("none")),
+        ];
+        loop {
+            self.redraw_screen()?;
+            let (width, _) = self.terminal_info().size()?;
+            // as much of the lines as fits, with room for the borders
+            let inner = lines
+                .iter()
+                .map(|l| l.chars().count())
+                .max()
+                .unwrap_or(0)
+                .min(usize::from(width.saturating_sub(4)));
+            let x = (width / 2).saturating_sub(u16::try_from(inner + 4).unwrap_or(u16::MAX) / 2);
+            let border = "─".repeat(inner + 2);
+            let rows = [format!("┌{border}┐")]
+                .into_iter()
+                .chain(lines.iter().map(|l| {
+                    let l: String = l.chars().take(inner).collect();
+                    format!("│ {l:<inner$} │")
+                }))
+                .chain([format!("└{border}┘")]);
+            for (y, row) in (2..).zip(rows) {
+                execute!(
+                    self.term,
+                    cursor::MoveTo(x, y),
+                    style::PrintStyledContent(row.grey().on_black().dim()),
+                )?;
+            }
+            self.write_status_bar(&StatusMessage::Info(
+                "Debug panel. Press any key to go back.".to_owned(),
+            ))?;
+            match self.next_event()? {
+                event::Event::Key(_) => break,
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+        execute!(self.term, terminal::Clear(terminal::ClearType::All))
+    }
+
+    /// Saves the tournament that just ended, and tells the best totals of
+    /// its seed so far, with its own among them.
+    fn save_tournament(&mut self) -> io::Result<Vec<usize>> {
+        let Some(t) = &self.tournament else {
+            return Ok(Vec::new());
+        };
+        let record = TournamentRecord::now(t.seed, t.score());
+        let leaderboard =
+            stats::append_tournament(&record).and_then(|()| stats::tournament_leaderboard(t.seed));
+        match leaderboard {
+            Ok(records) => Ok(records.iter().map(|r| r.score).collect()),
+            Err(e) => {
+                self.warn("save the tournament", e)?;
+                Ok(vec![record.score])
+            }
+        }
+    }
+
+    fn tournament_prompt(&mut self, state: &GameState) -> io::Result<Option<()>> {
+        let leaderboard = match &self.tournament {
+            Some(t) if t.is_finished() => self.save_tournament()?,
+            _ => Vec::new(),
+        };
+        let Some(t) = &self.tournament else {
+            return Ok(None);
+        };
+        let result = match state {
+            GameState::Win => "You won",
+            GameState::Loose | GameState::RevealedLoss => "You lose",
+        };
+        let message = if t.is_finished() {
+            let best: Vec<_> = leaderboard
+                .iter()
+                .take(5)
+                .map(ToString::to_string)
+                .collect();
+            StatusMessage::Info(format!(
+                "Tournament over! Score: {}, solver: {} (seed {}). Best for this seed: {}. \
+                 Press any key to quit.",
+                t.score(),
+                t.solver_score(),
+                t.seed,
+                best.join(", ")
+            ))
+        } else {
+            let text = format!(
+                "{result}! The word was {}. Round {}/{ROUNDS}, score {}. Next word? y/n ",
+                self.secret_word,
+                t.rounds.len(),
+                t.score()
+            );
+            match state {
+                GameState::Win => StatusMessage::Success(text),
+                GameState::Loose | GameState::RevealedLoss => StatusMessage::Error(text),
+            }
+        };
+        let finished = t.is_finished();
+
+        loop {
+            self.redraw_screen()?;
+            self.force_status_bar(&message)?;
+            match self.next_event()? {
+                event::Event::Key(_) if finished => return Ok(None),
+                event::Event::Key(k) => match k.code {
+                    KeyCode::Char('y') => return Ok(Some(())),
+                    KeyCode::Char('n') => return Ok(None),
+                    _ => {}
+                },
+                event::Event::Resize(width, height) => self.on_resize(width, height)?,
+                event::Event::Mouse(_) => (),
+            }
+        }
+    }
+
+    fn write_prompt(&mut self) -> io::Result<()> {
+        let mut badge = self.chain_status();
+        if is_pangram(letters_used(&self.guesses)) {
+            badge.push_str("Pangram! 🦋 ");
+        }
+        // superhard mode may leave no other choice, so no point in nagging
+        if self.guess.chars().count() == self.word_len()
+            && !self.config.superhard
+            && is_redundant(&self.guess, &self.keyboard)
+        {
+            return self.write_status_bar(&StatusMessage::Info(
+                "This guess contains no new information!".to_owned(),
+            ));
+        }
+        // the entropy is only known for 5-letter words
+        if self.guess.chars().count() == 5 && self.word_len() == 5 {
+            let info = self
+                .guess_info()
+                .map_or_else(|| "N/A".to_owned(), |bits| format!("~{bits:.1} bits"));
+            return self.write_status_bar(&StatusMessage::Info(format!(
+                "{badge}Info: {info}. Press Enter to submit! CTRL-C to quit."
+            )));
+        }
+        match self.config.puzzle_number() {
+            Some(n) => self.write_status_bar(&StatusMessage::Info(format!(
+                "{badge}Wordle #{n}. Type in a word and press Enter! CTRL-C to quit."
+            ))),
+            None => self.write_status_bar(&StatusMessage::Info(format!(
+                "{badge}Type in a word and press Enter! CTRL-C to quit."
+            ))),
+        }
+    }
+
+    /// Information the current guess is expected to give about the secret
+    /// word, see [`compute_entropy`], or `None` if it can't be guessed.
+    /// Redrawing asks for it again and again, so it's kept for a while.
+    fn guess_info(&mut self) -> Option<f64> {
+        if !self.dictionary.is_valid(&self.guess) {
+            return None;
+        }
+        if let Some((word, at, bits)) = &self.guess_info {
+            if *word == self.guess && at.elapsed() < GUESS_INFO_TTL {
+                return Some(*bits);
+            }
+        }
+        let bits = compute_entropy(&self.guess, &self.candidates);
+        self.guess_info = Some((self.guess.clone(), Instant::now(), bits));
+        Some(bits)
+    }
+
+    /// Smallest terminal the game can be drawn in.
+    fn min_size(&self) -> TerminalSize {
+        // the grid is centered, so it needs as much room to the right as it
+        // has to the left
+        let hints = if self.config.frequency_hints { 6 } else { 0 };
+        let grid_width = (self.grid_offset() + 1 + hints) * 2;
+        TerminalSize {
+            // without the keyboard only the grid has to fit
+            width: if self.config.no_keyboard {
+                grid_width
+            } else {
+                cmp::max(grid_width, 47)
+            },
+            height: match self.config.keyboard_position {
+                KeyboardPosition::Top if !self.config.no_keyboard => TOP_KEYBOARD_ROWS + 13,
+                _ => 13,
+            },
+        }
+    }
+
+    fn on_resize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        self.record_resize(width, height);
+        self.redraw_after_resize()
+    }
+
+    fn record_resize(&mut self, width: u16, height: u16) {
+        let old = std::mem::replace(&mut self.size, TerminalSize { width, height });
+        // everything is centered, so it moves whether the terminal grew or
+        // shrank
+        if self.size != old {
+            self.pending_resize = true;
+        }
+    }
+
+    fn redraw_after_resize(&mut self) -> io::Result<()> {
+        if std::mem::take(&mut self.pending_resize) {
+            execute!(self.term, terminal::Clear(terminal::ClearType::All))?;
+            self.redraw_screen()?;
+        }
+        Ok(())
+    }
+
+    /// Picks up resizes that happened since the last frame without blocking.
+    /// Any other events are kept for [`Self::next_event`].
+    fn poll_resize(&mut self) -> io::Result<()> {
+        if self.term.take_resize() {
+            let (width, height) = self.terminal_info().size()?;
+            self.record_resize(width, height);
+        }
+        // not through next_event, which would hand back the deferred events
+        while self.term.poll_event(Duration::ZERO)? {
+            match self.term.read_event()? {
+                event::Event::Resize(width, height) => self.record_resize(width, height),
+                e => self.deferred_events.push_back(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Drops the candidates ruled out by the guesses so far. Long lists are
+    /// filtered in the background, spinning a spinner in the status bar and
+    /// keeping up with resizes until it's done.
+    fn filter_candidates(&mut self) -> io::Result<()> {
+        let filter = self.word_filter();
+        if self.candidates.len() < BACKGROUND_FILTER_LEN {
+            self.candidates.retain(|c| filter.matches(c));
+            return Ok(());
+        }
+
+        let mut candidates = std::mem::take(&mut self.candidates);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            candidates.retain(|c| filter.matches(c));
+            sender.send(candidates).ok();
+        });
+
+        let mut frames = 0;
+        self.candidates = loop {
+            match receiver.try_recv() {
+                Ok(candidates) => break candidates,
+                Err(mpsc::TryRecvError::Empty) => {}
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    return Err(io::Error::other("filtering the words stopped unexpectedly"));
+                }
+            }
+            let frame = SPINNER[frames % SPINNER.len()];
+            self.write_status_bar(&StatusMessage::Info(format!(
+                "{frame} Narrowing down the words..."
+            )))?;
+            frames += 1;
+            if self.term.poll_event(Duration::from_millis(50))? {
+                self.poll_resize()?;
+            }
+        };
+        self.write_status_bar(&StatusMessage::Info(String::new()))
+    }
+
+    /// Starts working out the best next guess on another thread, for
+    /// [`poll_suggestion`](Self::poll_suggestion) to pick up. Entropy is only
+    /// scored for 5-letter words, so there's no suggestion for longer ones,
+    /// nor once the game is over.
+    fn start_suggestion(&mut self) {
+        self.suggestion = None;
+        self.cancel_suggestion();
+        if self.config.no_suggestions
+            || self.game_state().is_some()
+            || self.candidates.len() < 2
+            || self.candidates[0].chars().count() != 5
+        {
+            return;
+        }
+        let candidates = self.candidates.clone();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.suggestion_cancel = Arc::clone(&cancel);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            if let Some(best) = suggest_candidate(&candidates, &cancel) {
+                sender.send(best).ok();
+            }
+        });
+        self.pending_suggestion = Some(receiver);
+    }
+
+    /// Stops working out the suggestion, which is about guesses that are
+    /// gone.
+    fn cancel_suggestion(&mut self) {
+        self.suggestion_cancel.store(true, Ordering::Relaxed);
+        self.pending_suggestion = None;
+    }
+
+    /// Shows the suggestion once it's been worked out.
+    fn poll_suggestion(&mut self) -> io::Result<()> {
+        let Some(receiver) = &self.pending_suggestion else {
+            return Ok(());
+        };
+        match receiver.try_recv() {
+            Ok(suggestion) => self.suggestion = Some(suggestion),
+            Err(mpsc::TryRecvError::Empty) => return Ok(()),
+            Err(mpsc::TryRecvError::Disconnected) => {}
+        }
+        self.pending_suggestion = None;
+        execute!(self.term, cursor::SavePosition)?;
+        self.draw_suggestion()?;
+        execute!(self.term, cursor::RestorePosition)
+    }
+
+    /// Draws the best next guess, or the last word left, on `suggestion_row`.
+    fn draw_suggestion(&mut self) -> io::Result<()> {
+        let message = match (self.candidates.as_slice(), self.suggestion) {
+            ([word], _) => format!("Remaining word: {}", word.to_uppercase()),
+            (_, Some((word, bits))) => {
+                format!("Suggestion: {} ({bits:.1} bits)", word.to_uppercase())
+            }
+            (_, None) => String::new(),
+        };
+        self.draw_below(self.suggestion_row, &StatusMessage::Info(message))
+    }
+
+    fn log(&mut self, event: &GameEvent) {
+        if let Some(logger) = &mut self.logger {
+            logger.log_event(event);
+        }
+    }
+
+    fn next_event(&mut self) -> io::Result<event::Event> {
+        Ok(self.next_event_before(None)?.unwrap())
+    }
+
+    /// Waits for the next event, or until `deadline` passes in which case
+    /// there's none. While waiting, the time left is shown next to the row
+    /// being typed in.
+    fn next_event_before(&mut self, deadline: Option<Instant>) -> io::Result<Option<event::Event>> {
+        if let Some(e) = self.deferred_events.pop_front() {
+            return Ok(Some(e));
+        }
+        loop {
+            // waiting for a key could take forever, the game has to stop now
+            if INTERRUPT_FLAG.load(Ordering::Relaxed) {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            if self.term.take_resize() {
+                let (width, height) = self.terminal_info().size()?;
+                return Ok(Some(event::Event::Resize(width, height)));
+            }
+            if let Some(deadline) = deadline {
+                let left = deadline.saturating_duration_since(Instant::now());
+                if left.is_zero() {
+                    return Ok(None);
+                }
+                self.draw_timer(left)?;
+                self.draw_countdown(left)?;
+            }
+            if let Some(left) = self.session_time_left() {
+                if left.is_zero() {
+                    return Ok(None);
+                }
+                self.draw_session_timer(left)?;
+            }
+            self.poll_suggestion()?;
+            if self.term.poll_event(Duration::from_millis(100))? {
+                return self.term.read_event().map(Some);
+            }
+        }
+    }
+
+    /// When the current guess has to be in by, with `--timeout-per-guess`.
+    fn guess_deadline(&self) -> Option<Instant> {
+        self.config.per_guess_timer.map(|t| Instant::now() + t)
+    }
+
+    /// Time left of the session with `--max-time-per-session`.
+    fn session_time_left(&self) -> Option<Duration> {
+        self.session_timer
+            .map(|(start, limit)| limit.saturating_sub(start.elapsed()))
+    }
+
+    /// Shows the time left of the session in the top left corner, if it
+    /// doesn't run into the grid.
+    fn draw_session_timer(&mut self, left: Duration) -> io::Result<()> {
+        if !self.size.fits(self.min_size()) {
+            return Ok(());
+        }
+        let (width, height) = self.terminal_info().size()?;
+        // round up, so that 00:00 only shows once the time is up
+        let secs = left.as_millis().div_ceil(1000);
+        let text = format!("Session time left: {:02}:{:02}", secs / 60, secs % 60);
+        if text.len() + 1 > usize::from(self.free_columns(width, height, 0)) {
+            return Ok(());
+        }
+        execute!(
+            self.term,
+            cursor::SavePosition,
+            cursor::MoveTo(0, 0),
+            style::PrintStyledContent(text.dark_grey()),
+            cursor::RestorePosition
+        )
+    }
+
+    /// Shows the seconds left for the current guess to the right of its row.
+    fn draw_timer(&mut self, left: Duration) -> io::Result<()> {
+        if self.guesses.len() >= MAX_GUESSES || !self.size.fits(self.min_size()) {
+            return Ok(());
+        }
+        let (width, _) = self.terminal_info().size()?;
+        let x = width / 2 - self.grid_offset() + u16::try_from(self.word_len() * 4).unwrap();
+        let y = self.grid_top() + u16::try_from(self.guesses.len() * 2 + 1).unwrap();
+        // round up, so that 0 only shows once the time is up
+        let secs = left.as_millis().div_ceil(1000);
+        execute!(
+            self.term,
+            cursor::SavePosition,
+            cursor::MoveTo(x, y),
+            style::PrintStyledContent(format!(" {secs:>2}").yellow()),
+            cursor::RestorePosition,
+        )
+    }
+
+    /// Ticks the countdown to `left` and draws it next to the top right
+    /// corner of the grid, if there's room.
+    fn draw_countdown(&mut self, left: Duration) -> io::Result<()> {
+        let Some(countdown) = &mut self.countdown else {
+            return Ok(());
+        };
+        countdown.tick(left);
+        let countdown = *countdown;
+        if !self.size.fits(self.min_size()) {
+            return Ok(());
+        }
+        let (width, _) = self.terminal_info().size()?;
+        let x = width / 2 - self.grid_offset() + u16::try_from(self.word_len() * 4).unwrap() + 2;
+        if x + CountdownTimer::WIDTH > width {
+            return Ok(());
+        }
+        countdown.render(&mut self.term, x, 0)
+    }
+
+    /// Fills in the rest of the current guess with a random word starting
+    /// with it, when its time runs out. A guess that can't be completed is
+    /// replaced with a random candidate.
+    fn complete_guess(&mut self) {
+        let len = self.word_len();
+        if self.guess.chars().count() >= len {
+            return;
+        }
+        let mut rng = thread_rng();
+        let prefix = self.guess.as_str();
+        let candidates: Vec<_> = self
+            .candidates
+            .iter()
+            .copied()
+            .filter(|w| w.starts_with(prefix))
+            .collect();
+        let word = candidates.choose(&mut rng).copied().or_else(|| {
+            let guessable: Vec<_> = self
+                .config
+                .guessable_words()
+                .filter(|w| w.starts_with(prefix))
+                .collect();
+            guessable.choose(&mut rng).copied()
+        });
+        if let Some(word) = word.or_else(|| self.candidates.choose(&mut rng).copied()) {
+            self.guess = word.to_owned();
+        }
+    }
+
+    fn write_status_bar(&mut self, message: &StatusMessage) -> io::Result<()> {
+        if self.config.quiet {
+            return Ok(());
+        }
+        self.force_status_bar(message)
+    }
+
+    /// Like [`Game::write_status_bar`], but even with `--quiet`, for questions
+    /// the player has to answer to go on and warnings the game waits on.
+    fn force_status_bar(&mut self, message: &StatusMessage) -> io::Result<()> {
+        if !self.size.fits(self.min_size()) {
+            return Ok(());
+        }
+        let (width, height) = self.terminal_info().size()?;
+        // last row of the grid
+        let bottom = self.grid_top() + 12;
+        match height {
+            h if h <= bottom + 1 => Ok(()),
+            _ => {
+                // the line under the status bar takes what doesn't fit on it,
+                // when it's there
+                let (y, rows) = if height > bottom + 2 {
+                    (height - 2, 2)
+                } else {
+                    (height, 1)
+                };
+                let mut lines = if message.parts_len() > width {
+                    message.wrap(width)
+                } else {
+                    vec![message.clone()]
+                };
+                // more than fits is left for the terminal to wrap, like before
+                if lines.len() > rows {
+                    let rest: Vec<_> = lines
+                        .drain(rows - 1..)
+                        .map(|l| l.text().to_owned())
+                        .collect();
+                    lines.push(message.with_text(rest.join(" ")));
+                }
+                execute!(self.term, cursor::SavePosition)?;
+                for (row, y) in (y..y + u16::try_from(rows).unwrap()).enumerate() {
+                    execute!(
+                        self.term,
+                        cursor::MoveTo(0, y),
+                        terminal::Clear(terminal::ClearType::CurrentLine),
+                    )?;
+                    if let Some(line) = lines.get(row) {
+                        let len = line.parts_len();
+                        execute!(
+                            self.term,
+                            cursor::MoveTo((width / 2).saturating_sub(len / 2), y)
+                        )?;
+                        write!(self.term, "{line}")?;
+                    }
+                }
+                execute!(self.term, cursor::RestorePosition)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Shows "Warning: could not `what`" with `err` for a couple of seconds,
+    /// for things going wrong that don't stop the game.
+    fn warn(&mut self, what: &str, err: impl fmt::Display) -> io::Result<()> {
+        self.force_status_bar(&StatusMessage::Error(format!(
+            "Warning: could not {what}: {err}"
+        )))?;
+        thread::sleep(Duration::from_secs(2));
+        Ok(())
+    }
+
+    fn display_input(&mut self) -> io::Result<()> {
+        let (width, _) = self.terminal_info().size()?;
+        let x = width / 2 - self.grid_offset();
+        let top = self.grid_top();
+        execute!(self.term, cursor::MoveTo(x, top + 1), cursor::SavePosition,)?;
+        for GuessEntry {
+            word,
+            matches,
+            quality,
+        } in &self.guesses
+        {
+            // only the row being typed in is bright in focus mode
+            if self.focus {
+                execute!(self.term, style::SetAttribute(style::Attribute::Dim))?;
+            }
+            for (c, l) in word.chars().zip(matches) {
+                write!(self.term, "{}", tile_cell(&self.config, c, *l))?;
+            }
+            write!(self.term, " {quality:>2}")?;
+            if self.config.frequency_hints {
+                let tier = usize::from(word_frequency_tier(word));
+                write!(
+                    self.term,
+                    " {}{}",
+                    "★".repeat(tier + 1),
+                    "☆".repeat(4 - tier)
+                )?;
+            }
+            execute!(
+                self.term,
+                style::SetAttribute(style::Attribute::NormalIntensity),
+                cursor::RestorePosition,
+                cursor::MoveDown(2),
+                cursor::SavePosition,
+            )?;
+        }
+        if !self.guesses.is_empty() {
+            // total under the column of scores, next to the bottom border
+            let x = x + u16::try_from(self.word_len() * 4).unwrap();
+            let total = format!(" {:>2}", self.quality());
+            execute!(
+                self.term,
+                cursor::MoveTo(x, top + 12),
+                style::Print(total),
+                cursor::RestorePosition,
+            )?;
+        }
+        for c in self.guess.chars() {
+            // letters already known not to be in the word are greyed out
+            let c = match self.keyboard.letter_state(c) {
+                Some(Match::Incorrect) => uppercase_letter(c).dark_grey(),
+                _ => uppercase_letter(c).white(),
+            };
+            write!(self.term, " {c} │")?;
+            self.term.flush()?;
+        }
+        self.draw_ghost_tiles()
+    }
+
+    /// Shows the clues about the cells of the current row that are still
+    /// empty as dim letters, on the colour of what's known about them.
+    fn draw_ghost_tiles(&mut self) -> io::Result<()> {
+        if self.clues.is_empty() || self.guesses.len() >= MAX_GUESSES {
+            return Ok(());
+        }
+        let typed = self.guess.chars().count();
+        let (width, _) = self.terminal_info().size()?;
+        let x = width / 2 - self.grid_offset() + u16::try_from(typed * 4).unwrap();
+        let y = self.grid_top() + u16::try_from(self.guesses.len() * 2 + 1).unwrap();
+        execute!(self.term, cursor::MoveTo(x, y))?;
+        for position in typed..self.word_len() {
+            let clue = self
+                .clues
+                .iter()
+                .filter(|c| c.position == position && c.kind != Match::Incorrect)
+                .min_by_key(|c| c.kind != Match::Correct);
+            match clue {
+                Some(clue) => {
+                    let color = match clue.kind {
+                        Match::Correct => self.config.theme.correct,
+                        _ => self.config.theme.misplaced,
+                    };
+                    let c = uppercase_letter(clue.letter).with(color).dim();
+                    write!(self.term, " {c} │")?;
+                }
+                None => execute!(self.term, cursor::MoveRight(4))?,
+            }
+        }
+        // back to where the next letter goes
+        execute!(self.term, cursor::MoveTo(x, y))
+    }
+
+    /// Sum of the quality scores of all the guesses so far.
+    fn quality(&self) -> u32 {
+        self.guesses.iter().map(|g| u32::from(g.quality)).sum()
+    }
+
+    /// The current game as a [`GameRecord`].
+    #[must_use]
+    pub fn record(&self) -> GameRecord {
+        GameRecord {
+            secret_word: self.secret_word.to_owned(),
+            guesses: self.guesses.clone(),
+            number: self.config.puzzle_number(),
+        }
+    }
+
+    /// Quality of the game so far out of the best possible one, like
+    /// `Quality: 42/90`.
+    fn quality_summary(&self) -> String {
+        format!(
+            "Quality: {}/{}",
+            self.quality(),
+            self.word_len() * 3 * MAX_GUESSES
+        )
+    }
+
+    fn is_win(&self) -> bool {
+        let last = self.guesses.last().unwrap();
+        last.matches.iter().all(|m| *m == Match::Correct) && self.guesses.len() <= MAX_GUESSES
+    }
+
+    fn is_lose(&self) -> bool {
+        !self.is_win() && self.guesses.len() >= MAX_GUESSES
+    }
+
+    fn word_len(&self) -> usize {
+        self.config.mode.word_len()
+    }
+
+    /// Columns from the middle of the screen to the first cell of the grid.
+    fn grid_offset(&self) -> u16 {
+        (self.word_len() * 2 + 1).try_into().unwrap()
+    }
+
+    /// Row the keyboard starts at on a `width` by `height` screen, or `None`
+    /// if it's hidden or doesn't fit.
+    fn keyboard_row(&self, width: u16, height: u16) -> Option<u16> {
+        let keyboard_width = keyboard_width(self.config.locale.layout(), self.config.wide_keys);
+        if self.config.no_keyboard || width < keyboard_width + 7 {
+            return None;
+        }
+        match self.config.keyboard_position {
+            KeyboardPosition::Top => (height >= TOP_KEYBOARD_ROWS + 13).then_some(0),
+            KeyboardPosition::Bottom if height > 13 + 7 + 1 => Some(if height >= 13 + 1 + 12 {
+                height - 12
+            } else {
+                13
+            }),
+            KeyboardPosition::Bottom => None,
+        }
+    }
+
+    /// Columns at the start of `row` of a `width` by `height` screen left of
+    /// the grid and the keyboard, where they're on that row.
+    fn free_columns(&self, width: u16, height: u16, row: u16) -> u16 {
+        let mut free = width;
+        let top = self.grid_top();
+        if (top..top + 13).contains(&row) {
+            free = free.min((width / 2).saturating_sub(self.grid_offset()));
+        }
+        if let Some(keyboard) = self.keyboard_row(width, height) {
+            if (keyboard..keyboard + 7).contains(&row) {
+                let keyboard_width =
+                    keyboard_width(self.config.locale.layout(), self.config.wide_keys);
+                free = free.min((width / 2).saturating_sub(keyboard_width / 2));
+            }
+        }
+        free
+    }
+
+    /// Row the top border of the grid is on, below the keyboard when it's
+    /// drawn at the top.
+    fn grid_top(&self) -> u16 {
+        match self.terminal_info().size() {
+            Ok((width, height))
+                if self.config.keyboard_position == KeyboardPosition::Top
+                    && self.keyboard_row(width, height).is_some() =>
+            {
+                TOP_KEYBOARD_ROWS
+            }
+            _ => 0,
+        }
+    }
+
+    fn draw_grid(&mut self) -> io::Result<()> {
+        let (width, height) = self.terminal_info().size()?;
+
+        let top = self.grid_top();
+        let x = width / 2 - self.grid_offset() - 1;
+        execute!(self.term, cursor::MoveTo(x, top))?;
+        for line in grid_lines(self.word_len(), MAX_GUESSES) {
+            execute!(
+                self.term,
+                cursor::SavePosition,
+                style::Print(line),
+                cursor::RestorePosition,
+                cursor::MoveDown(1),
+            )?;
+        }
+        // first free row below the grid or the keyboard
+        let mut below = top + 13;
+        if let Some(row) = self.keyboard_row(width, height) {
+            let keyboard_width = keyboard_width(self.config.locale.layout(), self.config.wide_keys);
+            execute!(
+                self.term,
+                cursor::MoveTo(width / 2 - keyboard_width / 2, row)
+            )?;
+            if self.config.smart_keyboard {
+                let constraints = ConstraintSet::new(&infer_constraints(&self.guesses));
+                write!(
+                    self.term,
+                    "{}",
+                    SmartKeyboard::new(&self.keyboard, &constraints)
+                )?;
+            } else {
+                write!(self.term, "{}", self.keyboard)?;
+            }
+            below = below.max(row + 7);
+        }
+        if !self.guesses.is_empty() {
+            let meter = match self.candidates.len() {
+                0 => StatusMessage::Error("No words left, this shouldn't happen!".to_owned()),
+                1 => StatusMessage::Success("Only 1 word left!".to_owned()),
+                n => StatusMessage::Info(format!("Remaining: {n} words")),
+            };
+            self.draw_below(below, &meter)?;
+            below += 1;
+        }
+        if !self.config.no_suggestions && !self.guesses.is_empty() {
+            self.suggestion_row = below;
+            self.draw_suggestion()?;
+            below += 1;
+        }
+        if self.config.word_graph {
+            for line in self.word_graph() {
+                self.draw_below(below, &StatusMessage::Info(line))?;
+                below += 1;
+            }
+        }
+        if self.show_untried {
+            let letters: Vec<_> = self
+                .hint_remaining_letters()
+                .into_iter()
+                .map(uppercase_letter)
+                .map(String::from)
+                .collect();
+            let text = format!("Easy mode, untried letters: {}", letters.join(" "));
+            self.draw_below(below, &StatusMessage::Info(text))?;
+        }
+        Ok(())
+    }
+
+    /// Lines linking the last guess to each earlier one with the letters they
+    /// share, the most recent first, like `SLATE ─A─ ┄E┄ CRANE`. Letters
+    /// correct in the last guess are joined with `─`, misplaced ones with `┄`
+    /// and incorrect ones aren't shown.
+    fn word_graph(&self) -> Vec<String> {
+        let Some((curr, earlier)) = self.guesses.split_last() else {
+            return Vec::new();
+        };
+        earlier
+            .iter()
+            .rev()
+            .filter_map(|prev| {
+                let edges: Vec<_> = shared_letters(&curr.word, &prev.word)
+                    .into_iter()
+                    .filter_map(|(c, i, _)| {
+                        let edge = match curr.matches[i] {
+                            Match::Correct => '─',
+                            Match::Misplaced => '┄',
+                            Match::Incorrect => return None,
+                        };
+                        Some(format!("{edge}{}{edge}", uppercase_letter(c)))
+                    })
+                    .collect();
+                (!edges.is_empty()).then(|| {
+                    format!(
+                        "{} {} {}",
+                        prev.word.to_uppercase(),
+                        edges.join(" "),
+                        curr.word.to_uppercase()
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Draws `message` centered on row `y` below the grid and the keyboard,
+    /// unless it'd overlap the status bar or not fit.
+    fn draw_below(&mut self, y: u16, message: &StatusMessage) -> io::Result<()> {
+        let (width, height) = self.terminal_info().size()?;
+        let status_bar = if height > self.grid_top() + 14 {
+            height - 2
+        } else {
+            height
+        };
+        let len = message.parts_len();
+        if y >= status_bar || len > width {
+            return Ok(());
+        }
+        execute!(
+            self.term,
+            cursor::MoveTo(0, y),
+            terminal::Clear(terminal::ClearType::CurrentLine),
+            cursor::MoveTo(width / 2 - len / 2, y),
+            style::Print(message),
+        )
+    }
+
+    fn redraw_screen(&mut self) -> io::Result<()> {
+        let min = self.min_size();
+        if !self.size.fits(min) {
+            return execute!(
+                self.term,
+                cursor::MoveTo(0, 0),
+                style::Print(format!(
+                    "Terminal too small, resize it to at least {}x{}",
+                    min.width, min.height
+                )),
+            );
+        }
+        let (width, height) = self.terminal_info().size()?;
+        execute!(
+            self.term,
+            terminal::SetSize(cmp::max(width, min.width), cmp::max(height, min.height))
         )?;
         self.draw_grid()?;
         self.display_input()?;
+        if let Some(seed) = self.seed() {
+            let badge_len = format!("[seed: {seed}]").len();
+            // under the time left of the session, when there's one
+            let row = u16::from(self.session_timer.is_some());
+            if badge_len < usize::from(self.free_columns(width, height, row)) {
+                draw_seed_badge(&mut self.term, seed, 0, row)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Seed the secret words are picked with, if they aren't random.
+    fn seed(&self) -> Option<u64> {
+        self.config
+            .seed
+            .or_else(|| self.tournament.as_ref().map(|t| t.seed))
+    }
+}
+
+/// A cell of the grid holding the letter `c` marked as `m`, either as a
+/// coloured letter or as an emoji.
+fn tile_cell(config: &Config, c: char, m: Match) -> String {
+    if config.emoji {
+        let emoji = config.emoji_style.symbol(m);
+        // emoji are usually two columns wide, so the right padding shrinks
+        let pad = 2usize.saturating_sub(emoji.width());
+        format!(" {emoji}{:pad$}│", "")
+    } else {
+        format!(" {} │", config.theme.tile(c, m))
+    }
+}
+
+/// Lines of an empty grid for `max_guesses` words of `word_len` letters.
+fn grid_lines(word_len: usize, max_guesses: usize) -> Vec<String> {
+    let line = |left, middle, right, fill| {
+        let mut line = String::from(left);
+        for i in 0..word_len {
+            line.push_str(fill);
+            line.push(if i + 1 == word_len { right } else { middle });
+        }
+        line
+    };
+
+    let mut lines = vec![line('┌', '┬', '┐', "───")];
+    for i in 0..max_guesses {
+        lines.push(line('│', '│', '│', "   "));
+        if i + 1 < max_guesses {
+            lines.push(line('├', '┼', '┤', "───"));
+        }
+    }
+    lines.push(line('└', '┴', '┘', "───"));
+    lines
+}
+
+/// Renders an empty grid as plain text, one line per row: a row of letters
+/// for each guess, with separators only between them.
+///
+/// ```
+/// use yawc::game::draw_grid_to_string;
+///
+/// let grid = draw_grid_to_string(5, 6);
+/// let rows = |start| grid.lines().filter(|l| l.starts_with(start)).count();
+/// assert_eq!(rows('│'), 6);
+/// assert_eq!(rows('├'), 5);
+/// assert_eq!(grid.lines().count(), 13);
+/// ```
+#[cfg(any(test, feature = "testing"))]
+#[must_use]
+pub fn draw_grid_to_string(word_len: usize, max_guesses: usize) -> String {
+    grid_lines(word_len, max_guesses).join("\n")
+}
+
+/// Guesses of a game, in the order they were made.
+pub type GuessHistory = Vec<GuessEntry>;
+
+/// Defines a struct or a fieldless enum along with `DOC`, its doc comment,
+/// and `ITEM_DOCS`, the doc comments of its fields or variants, so that
+/// [`GameRecord::json_schema`] describes them the same way the docs do.
+///
+/// The doc comments have to come before the `derive`.
+macro_rules! documented {
+    (
+        $(#[doc = $doc:literal])*
+        #[derive($($derive:path),* $(,)?)]
+        pub struct $name:ident {
+            $($(#[doc = $item_doc:literal])* pub $item:ident: $ty:ty,)*
+        }
+    ) => {
+        $(#[doc = $doc])*
+        #[derive($($derive),*)]
+        pub struct $name {
+            $($(#[doc = $item_doc])* pub $item: $ty,)*
+        }
+
+        documented!(@docs $name, [$($doc)*], [$($item [$($item_doc)*])*]);
+    };
+    (
+        $(#[doc = $doc:literal])*
+        #[derive($($derive:path),* $(,)?)]
+        pub enum $name:ident {
+            $($(#[doc = $item_doc:literal])* $item:ident,)*
+        }
+    ) => {
+        $(#[doc = $doc])*
+        #[derive($($derive),*)]
+        pub enum $name {
+            $($(#[doc = $item_doc])* $item,)*
+        }
+
+        documented!(@docs $name, [$($doc)*], [$($item [$($item_doc)*])*]);
+    };
+    (@docs $name:ident, [$($doc:literal)*], [$($item:ident [$($item_doc:literal)*])*]) => {
+        impl $name {
+            const DOC: &'static str = concat!($($doc, "\n",)*);
+            const ITEM_DOCS: &'static [(&'static str, &'static str)] =
+                &[$((stringify!($item), concat!($($item_doc, "\n",)*)),)*];
+        }
+    };
+}
+
+/// Turns a doc comment into one line of plain text, for a JSON Schema
+/// description.
+fn schema_description(doc: &str) -> String {
+    doc.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("[`", "")
+        .replace("`]", "")
+        .replace('`', "")
+}
+
+/// Description of `item` among the `ITEM_DOCS` of a [`documented!`] type.
+fn item_description(item_docs: &[(&str, &str)], item: &str) -> String {
+    let doc = item_docs.iter().find(|(name, _)| *name == item);
+    schema_description(doc.map_or("", |(_, doc)| doc))
+}
+
+documented! {
+    /// A finished game: the secret word and every guess made.
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+    pub struct GameRecord {
+        /// The word that had to be guessed.
+        pub secret_word: String,
+        /// Guesses of the game, in the order they were made.
+        pub guesses: GuessHistory,
+        /// Number of the puzzle, given with `--number` or worked out from
+        /// the date with `--daily`.
+        pub number: Option<u32>,
+    }
+}
+
+impl GameRecord {
+    /// JSON Schema of a record serialized as JSON, for `--json-schema`.
+    ///
+    /// ```
+    /// use yawc::game::{GameRecord, GuessEntry, Match::*};
+    ///
+    /// let schema = GameRecord::json_schema();
+    /// let schema: serde_json::Value = serde_json::from_str(&schema.to_string()).unwrap();
+    /// for key in ["$schema", "title", "description", "type", "properties", "required"] {
+    ///     assert!(schema.get(key).is_some(), "no {key}");
+    /// }
+    ///
+    /// // and it describes what records serialize to
+    /// let record = GameRecord {
+    ///     secret_word: "crane".to_owned(),
+    ///     guesses: vec![GuessEntry::new("crane".to_owned(), vec![Correct; 5])],
+    ///     number: None,
+    /// };
+    /// let record = serde_json::to_value(&record).unwrap();
+    /// let properties = schema["properties"].as_object().unwrap();
+    /// assert!(record.as_object().unwrap().keys().eq(properties.keys()));
+    /// ```
+    #[must_use]
+    pub fn json_schema() -> serde_json::Value {
+        let field = |name| item_description(GameRecord::ITEM_DOCS, name);
+        let entry_field = |name| item_description(GuessEntry::ITEM_DOCS, name);
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "GameRecord",
+            "description": schema_description(GameRecord::DOC),
+            "type": "object",
+            "properties": {
+                "secret_word": {
+                    "description": field("secret_word"),
+                    "type": "string",
+                },
+                "guesses": {
+                    "description": field("guesses"),
+                    "type": "array",
+                    "items": { "$ref": "#/$defs/GuessEntry" },
+                },
+                "number": {
+                    "description": field("number"),
+                    "type": ["integer", "null"],
+                    "minimum": 0,
+                },
+            },
+            "required": ["secret_word", "guesses", "number"],
+            "additionalProperties": false,
+            "$defs": {
+                "GuessEntry": {
+                    "description": schema_description(GuessEntry::DOC),
+                    "type": "object",
+                    "properties": {
+                        "word": {
+                            "description": entry_field("word"),
+                            "type": "string",
+                        },
+                        "matches": {
+                            "description": entry_field("matches"),
+                            "type": "array",
+                            "items": { "$ref": "#/$defs/Match" },
+                        },
+                        "quality": {
+                            "description": entry_field("quality"),
+                            "type": "integer",
+                            "minimum": 0,
+                            "maximum": 255,
+                        },
+                    },
+                    "required": ["word", "matches", "quality"],
+                    "additionalProperties": false,
+                },
+                "Match": {
+                    "description": schema_description(Match::DOC),
+                    "enum": Match::ITEM_DOCS.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+                },
+            },
+        })
+    }
+
+    /// Number of guesses it took to find the word, [`None`] if it wasn't.
+    #[must_use]
+    pub fn guesses_used(&self) -> Option<usize> {
+        let found = self
+            .guesses
+            .last()
+            .is_some_and(|g| g.matches.iter().all(|m| *m == Match::Correct));
+        found.then_some(self.guesses.len())
+    }
+
+    /// Points scored by the game, see [`compute_score`].
+    #[must_use]
+    pub fn score(&self, config: &Config) -> u32 {
+        compute_score(self, config)
+    }
+}
+
+documented! {
+    /// A submitted guess along with how it matched the secret word.
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+    pub struct GuessEntry {
+        /// The word guessed.
+        pub word: String,
+        /// How each letter of the word matched, in order.
+        pub matches: Vec<Match>,
+        /// How much the guess revealed: 3 points for every correct letter, 2
+        /// for every misplaced one and 1 for every incorrect one, as
+        /// [`match_quality_score`] counts them.
+        pub quality: u8,
+    }
+}
+
+impl GuessEntry {
+    #[must_use]
+    pub fn new(word: String, matches: Vec<Match>) -> Self {
+        let quality = match_quality_score(&matches);
+        Self {
+            word,
+            matches,
+            quality,
+        }
+    }
+
+    /// Whether every letter of the guess was already known to be correct or
+    /// incorrect on `keyboard`, so that it brought no new information.
+    ///
+    /// ```
+    /// use yawc::{game::{GuessEntry, Match::*}, keyboard::Keyboard};
+    ///
+    /// let entry = GuessEntry::new("tatty".to_owned(), vec![Correct; 5]);
+    /// let mut keyboard = Keyboard::default();
+    /// assert!(!entry.is_redundant(&keyboard));
+    ///
+    /// keyboard.mark_letter('t', Correct);
+    /// keyboard.mark_letter('a', Incorrect);
+    /// assert!(!entry.is_redundant(&keyboard));
+    ///
+    /// // misplaced letters could still be anywhere
+    /// keyboard.mark_letter('y', Misplaced);
+    /// assert!(!entry.is_redundant(&keyboard));
+    ///
+    /// keyboard.mark_letter('y', Correct);
+    /// assert!(entry.is_redundant(&keyboard));
+    /// ```
+    #[must_use]
+    pub fn is_redundant(&self, keyboard: &Keyboard) -> bool {
+        is_redundant(&self.word, keyboard)
+    }
+}
+
+/// The squares of `guesses`, one row per guess, under a `yawc 3/6` header,
+/// `X` being the count if the last guess wasn't right, to be shared without
+/// giving the words away. The puzzle `number`, if any, goes before the
+/// count like in `yawc #1234 3/6`. The [`share_link`] of the game, if it has
+/// one, comes last.
+///
+/// ```
+/// use yawc::{game::{share_text, GuessEntry, Match::*}, theme::EmojiStyle};
+///
+/// let history = [
+///     GuessEntry::new("slate".to_owned(), vec![Incorrect, Incorrect, Correct, Incorrect, Misplaced]),
+///     GuessEntry::new("crane".to_owned(), vec![Correct; 5]),
+/// ];
+/// assert_eq!(
+///     share_text(&history, None, 6, EmojiStyle::Wordle),
+///     "yawc 2/6\n\n⬛⬛🟩⬛🟨\n🟩🟩🟩🟩🟩\n\nhttps://zohnannor.github.io/yawc/?h=c2xhdGVjY3JhbmXy"
+/// );
+/// assert!(share_text(&history[..1], None, 6, EmojiStyle::Wordle).starts_with("yawc X/6"));
+/// assert!(share_text(&history, Some(1234), 6, EmojiStyle::Wordle).starts_with("yawc #1234 2/6\n"));
+/// ```
+#[must_use]
+pub fn share_text(
+    guesses: &[GuessEntry],
+    number: Option<u32>,
+    max_guesses: usize,
+    style: EmojiStyle,
+) -> String {
+    let number = number.map(|n| format!("#{n} ")).unwrap_or_default();
+    let mut text = format!("yawc {number}{}/{max_guesses}\n", share_count(guesses));
+    for guess in guesses {
+        text.push('\n');
+        text.extend(guess.matches.iter().map(|&m| style.symbol(m)));
+    }
+    if let Some(link) = share_link(guesses) {
+        text.push_str("\n\n");
+        text.push_str(&link);
+    }
+    text
+}
+
+/// The web page showing a game shared with [`share_link`].
+pub const SHARE_VIEWER_URL: &str = "https://zohnannor.github.io/yawc/";
+
+/// A link to [`SHARE_VIEWER_URL`] with `guesses` in its `?h=` parameter, as
+/// a [`serialize_guess_history`] string. Only games of 5-letter ASCII words
+/// can be encoded, others have no link.
+///
+/// ```
+/// use yawc::game::{share_link, GuessEntry, Match::*};
+///
+/// let crane = GuessEntry::new("crane".to_owned(), vec![Correct; 5]);
+/// assert_eq!(
+///     share_link(&[crane]).as_deref(),
+///     Some("https://zohnannor.github.io/yawc/?h=Y3JhbmXy")
+/// );
+/// let ete = GuessEntry::new("été".to_owned(), vec![Correct; 3]);
+/// assert_eq!(share_link(&[ete]), None);
+/// ```
+#[must_use]
+pub fn share_link(guesses: &[GuessEntry]) -> Option<String> {
+    guesses
+        .iter()
+        .all(|g| g.word.len() == 5 && g.word.bytes().all(|b| b.is_ascii_lowercase()))
+        .then(|| format!("{SHARE_VIEWER_URL}?h={}", serialize_guess_history(guesses)))
+}
+
+/// Number of guesses it took for [`share_text`], or `X` if the last one
+/// wasn't right.
+fn share_count(guesses: &[GuessEntry]) -> String {
+    let won = guesses
+        .last()
+        .is_some_and(|g| g.matches.iter().all(|&m| m == Match::Correct));
+    if won {
+        guesses.len().to_string()
+    } else {
+        "X".to_owned()
+    }
+}
+
+/// The URL-safe base64 alphabet, so that encoded histories can go in links.
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes `guesses` of 5-letter ASCII words as URL-safe base64. Each guess
+/// takes 6 bytes: the letters of the word, then its matches as a base-3
+/// number with `Incorrect` as 0, `Misplaced` as 1 and `Correct` as 2, the
+/// first letter's being the least significant digit. 6 bytes make exactly 8
+/// characters, so there's never any padding.
+///
+/// ```
+/// use yawc::game::{deserialize_guess_history, serialize_guess_history, GuessEntry, Match::*};
+///
+/// let history = vec![
+///     GuessEntry::new("slate".to_owned(), vec![Incorrect, Incorrect, Correct, Incorrect, Misplaced]),
+///     GuessEntry::new("crane".to_owned(), vec![Correct; 5]),
+/// ];
+/// let encoded = serialize_guess_history(&history);
+/// assert_eq!(encoded.len(), 16);
+/// assert_eq!(deserialize_guess_history(&encoded), Some(history));
+/// assert_eq!(serialize_guess_history(&[]), "");
+/// ```
+#[must_use]
+pub fn serialize_guess_history(guesses: &[GuessEntry]) -> String {
+    let bytes: Vec<u8> = guesses
+        .iter()
+        .flat_map(|g| {
+            let matches = g.matches.iter().rev().fold(0, |n, m| {
+                n * 3
+                    + match m {
+                        Match::Incorrect => 0,
+                        Match::Misplaced => 1,
+                        Match::Correct => 2,
+                    }
+            });
+            g.word.bytes().chain([matches])
+        })
+        .collect();
+    bytes
+        .chunks(3)
+        .flat_map(|chunk| {
+            let n =
+                chunk.iter().fold(0u32, |n, &b| n << 8 | u32::from(b)) << (8 * (3 - chunk.len()));
+            (0..=chunk.len())
+                .map(move |i| char::from(BASE64_URL[(n >> (18 - 6 * i) & 63) as usize]))
+        })
+        .collect()
+}
+
+/// Decodes a history encoded by [`serialize_guess_history`], or `None` if `s`
+/// isn't one.
+///
+/// ```
+/// use yawc::game::deserialize_guess_history;
+///
+/// assert_eq!(deserialize_guess_history(""), Some(Vec::new()));
+/// // not base64
+/// assert_eq!(deserialize_guess_history("slate,crane"), None);
+/// // "slate" along with a match byte out of range
+/// assert_eq!(deserialize_guess_history("c2xhdGX_"), None);
+/// ```
+#[must_use]
+pub fn deserialize_guess_history(s: &str) -> Option<Vec<GuessEntry>> {
+    if !s.len().is_multiple_of(8) {
+        return None;
+    }
+    let sextets = s
+        .bytes()
+        .map(|b| BASE64_URL.iter().position(|&c| c == b))
+        .collect::<Option<Vec<_>>>()?;
+    let bytes: Vec<u8> = sextets
+        .chunks(4)
+        .flat_map(|chunk| {
+            let n = chunk
+                .iter()
+                .fold(0u32, |n, &i| n << 6 | u32::try_from(i).unwrap_or(0));
+            n.to_be_bytes().into_iter().skip(1)
+        })
+        .collect();
+    bytes
+        .chunks(6)
+        .map(|entry| {
+            let (word, &[mut n]) = entry.split_at(5) else {
+                return None;
+            };
+            if !word.iter().all(u8::is_ascii_lowercase) || n >= 3u8.pow(5) {
+                return None;
+            }
+            let matches = (0..5)
+                .map(|_| {
+                    let m = match n % 3 {
+                        0 => Match::Incorrect,
+                        1 => Match::Misplaced,
+                        _ => Match::Correct,
+                    };
+                    n /= 3;
+                    m
+                })
+                .collect();
+            Some(GuessEntry::new(
+                String::from_utf8(word.to_vec()).ok()?,
+                matches,
+            ))
+        })
+        .collect()
+}
 
-        Ok(())
+/// Letters `a..=z` tried in `guesses`, whatever they matched, as a bitmask
+/// with bit 0 for `a`. Other letters, like accented ones, are left out.
+///
+/// ```
+/// use yawc::game::{letters_used, GuessEntry, Match::*};
+///
+/// let guesses = [GuessEntry::new("abbey".to_owned(), vec![Incorrect; 5])];
+/// assert_eq!(letters_used(&guesses), 0b1_0000_0000_0000_0000_0001_0011);
+/// ```
+#[must_use]
+pub fn letters_used(guesses: &[GuessEntry]) -> u32 {
+    guesses
+        .iter()
+        .flat_map(|g| g.word.bytes())
+        .filter(u8::is_ascii_lowercase)
+        .fold(0, |mask, b| mask | 1 << (b - b'a'))
+}
+
+/// Whether `mask` from [`letters_used`] has all 26 letters, which is a
+/// pangram in Wordle terms.
+///
+/// ```
+/// use yawc::game::{is_pangram, letters_used, GuessEntry, Match::*};
+///
+/// let words = ["fjord", "gucks", "nymph", "vibex", "waltz", "quack"];
+/// let guesses: Vec<_> = words
+///     .iter()
+///     .map(|w| GuessEntry::new((*w).to_owned(), vec![Incorrect; 5]))
+///     .collect();
+/// assert!(is_pangram(letters_used(&guesses)));
+/// assert!(!is_pangram(letters_used(&guesses[..5])));
+/// ```
+#[must_use]
+pub fn is_pangram(mask: u32) -> bool {
+    mask & ((1 << 26) - 1) == (1 << 26) - 1
+}
+
+/// Letters `a` and `b` have in common, with their positions in `a` and in
+/// `b`, in the order they appear in `a`. Letters at the same position in
+/// both are paired up first, and each position is only used once, so a
+/// letter `a` has twice is only shared twice if `b` has it twice too.
+///
+/// ```
+/// use yawc::game::shared_letters;
+///
+/// assert_eq!(
+///     shared_letters("crane", "slate"),
+///     vec![('a', 2, 2), ('e', 4, 4)],
+/// );
+/// assert_eq!(
+///     shared_letters("eerie", "crepe"),
+///     vec![('e', 0, 2), ('r', 2, 1), ('e', 4, 4)],
+/// );
+/// ```
+#[must_use]
+pub fn shared_letters(a: &str, b: &str) -> Vec<(char, usize, usize)> {
+    let a: Vec<_> = a.chars().collect();
+    let b: Vec<_> = b.chars().collect();
+    let mut pairs: Vec<Option<usize>> = a
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (b.get(i) == Some(c)).then_some(i))
+        .collect();
+    let mut used: Vec<_> = (0..b.len()).map(|j| pairs.contains(&Some(j))).collect();
+    for (i, c) in a.iter().enumerate() {
+        if pairs[i].is_some() {
+            continue;
+        }
+        if let Some(j) = (0..b.len()).find(|&j| !used[j] && b[j] == *c) {
+            used[j] = true;
+            pairs[i] = Some(j);
+        }
+    }
+    a.into_iter()
+        .zip(pairs)
+        .enumerate()
+        .filter_map(|(i, (c, j))| Some((c, i, j?)))
+        .collect()
+}
+
+/// Letters only in `a`, in both words, and only in `b`, each in the order
+/// they first appear.
+///
+/// ```
+/// use yawc::game::letter_sets_diff;
+///
+/// assert_eq!(
+///     letter_sets_diff("crane", "slate"),
+///     (vec!['c', 'r', 'n'], vec!['a', 'e'], vec!['s', 'l', 't']),
+/// );
+/// ```
+#[must_use]
+pub fn letter_sets_diff(a: &str, b: &str) -> (Vec<char>, Vec<char>, Vec<char>) {
+    let mut only_a = Vec::new();
+    let mut shared = Vec::new();
+    let mut only_b = Vec::new();
+    for c in a.chars() {
+        let set = if b.contains(c) {
+            &mut shared
+        } else {
+            &mut only_a
+        };
+        if !set.contains(&c) {
+            set.push(c);
+        }
+    }
+    for c in b.chars() {
+        if !a.contains(c) && !only_b.contains(&c) {
+            only_b.push(c);
+        }
+    }
+    (only_a, shared, only_b)
+}
+
+/// How each letter's match changed from `prev` to `curr`: [`Some(true)`] if
+/// it got better, like from [`Match::Misplaced`] to [`Match::Correct`],
+/// [`Some(false)`] if it got worse and [`None`] if it stayed the same.
+///
+/// ```
+/// use yawc::game::{match_diff, Match::*};
+///
+/// assert_eq!(
+///     match_diff(
+///         [Incorrect, Misplaced, Correct, Correct, Incorrect],
+///         [Misplaced, Correct, Incorrect, Correct, Incorrect],
+///     ),
+///     [Some(true), Some(true), Some(false), None, None],
+/// );
+/// ```
+#[must_use]
+pub fn match_diff(prev: [Match; 5], curr: [Match; 5]) -> [Option<bool>; 5] {
+    std::array::from_fn(|i| {
+        match match_quality_score(&[curr[i]]).cmp(&match_quality_score(&[prev[i]])) {
+            cmp::Ordering::Greater => Some(true),
+            cmp::Ordering::Less => Some(false),
+            cmp::Ordering::Equal => None,
+        }
+    })
+}
+
+/// Whether every letter of `word` is already known to be either correct or
+/// incorrect, so guessing it can only tell where the misplaced ones aren't.
+fn is_redundant(word: &str, keyboard: &Keyboard) -> bool {
+    word.chars().all(|c| {
+        matches!(
+            keyboard.letter_state(c),
+            Some(Match::Correct | Match::Incorrect)
+        )
+    })
+}
+
+/// Rates how much a guess revealed: 3 points for every correct letter, 2 for
+/// every misplaced one and 1 for every incorrect one, so between 5 and 15 for
+/// a 5-letter word.
+///
+/// ```
+/// use yawc::game::{match_quality_score, Match::*};
+///
+/// assert_eq!(
+///     match_quality_score(&[Correct, Misplaced, Incorrect, Incorrect, Correct]),
+///     10,
+/// );
+/// ```
+#[must_use]
+pub fn match_quality_score(matches: &[Match]) -> u8 {
+    matches
+        .iter()
+        .map(|m| match m {
+            Match::Correct => 3,
+            Match::Misplaced => 2,
+            Match::Incorrect => 1,
+        })
+        .sum()
+}
+
+/// In hard mode what earlier guesses revealed must be used again: letters
+/// found in place must stay there, and misplaced ones must be somewhere in
+/// the word, as many times as they were found. Returns an error message if
+/// `candidate` doesn't.
+#[must_use]
+pub fn hard_mode_check(guesses: &[GuessEntry], candidate: &str) -> Option<String> {
+    let letters: Vec<char> = candidate.chars().collect();
+    for (i, entry) in guesses.iter().enumerate() {
+        let mut found: Vec<char> = Vec::new();
+        for (pos, (c, m)) in entry.word.chars().zip(&entry.matches).enumerate() {
+            if *m == Match::Correct && letters.get(pos) != Some(&c) {
+                return Some(format!(
+                    "Letter {} must be {}, as found in guess {}!",
+                    pos + 1,
+                    uppercase_letter(c),
+                    i + 1
+                ));
+            }
+            if *m != Match::Incorrect {
+                found.push(c);
+            }
+        }
+        if let Some(&c) = found.iter().find(|&&c| {
+            let count = |word: &[char]| word.iter().filter(|&&l| l == c).count();
+            count(&letters) < count(&found)
+        }) {
+            return Some(format!(
+                "Letter {} found in guess {} must be used!",
+                uppercase_letter(c),
+                i + 1
+            ));
+        }
+    }
+    None
+}
+
+/// In superhard mode letters ruled out by earlier guesses can't be used
+/// again. Returns an error message if `candidate` uses any of them.
+///
+/// A letter is only ruled out when none of its occurrences in a guess was
+/// correct or misplaced, as otherwise it's just a repeat that isn't there.
+#[must_use]
+pub fn superhard_mode_check(guesses: &[GuessEntry], candidate: &str) -> Option<String> {
+    for (i, entry) in guesses.iter().enumerate() {
+        let present: Vec<char> = entry
+            .word
+            .chars()
+            .zip(&entry.matches)
+            .filter(|(_, m)| **m != Match::Incorrect)
+            .map(|(c, _)| c)
+            .collect();
+        if let Some(c) = entry
+            .word
+            .chars()
+            .zip(&entry.matches)
+            .filter(|(c, m)| **m == Match::Incorrect && !present.contains(c))
+            .map(|(c, _)| c)
+            .find(|c| candidate.contains(*c))
+        {
+            return Some(format!(
+                "Letter {} was ruled out in guess {}!",
+                uppercase_letter(c),
+                i + 1
+            ));
+        }
     }
+    None
 }
 
-enum GameState {
+/// How a game ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameState {
     Win,
     Loose,
+    /// The player gave up and revealed the secret word with `Ctrl+R`.
+    RevealedLoss,
+}
+
+documented! {
+    /// How a letter of a guess matched the secret word.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+    pub enum Match {
+        Correct,
+        Misplaced,
+        Incorrect,
+    }
+}
+
+/// Prints `✓`, `~` or `✗`, or with the alternate flag (`{:#}`) the `C`, `M`
+/// or `I` abbreviations.
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match (self, f.alternate()) {
+            (Match::Correct, false) => "✓",
+            (Match::Misplaced, false) => "~",
+            (Match::Incorrect, false) => "✗",
+            (Match::Correct, true) => "C",
+            (Match::Misplaced, true) => "M",
+            (Match::Incorrect, true) => "I",
+        };
+        f.write_str(s)
+    }
 }
 
+/// How a game played with [`Game::play_guesses`] went.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) enum Match {
-    Correct,
-    Misplaced,
-    Incorrect,
+pub struct GameResult {
+    /// How it ended, [`None`] if it hasn't yet.
+    pub state: Option<GameState>,
+    pub guesses_used: usize,
+}
+
+/// Why a guess made with [`Game::inject_guess`] didn't go in.
+#[derive(Debug)]
+pub enum GameError {
+    /// The word isn't in the word list, or isn't as long as the secret word.
+    InvalidWord(String),
+    /// The word breaks a rule of `--hard`, `--superhard` or `--crossword`, as
+    /// said.
+    RuleViolation(String),
+    /// The game was already won or lost.
+    GameOver,
+    /// Drawing the game failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::InvalidWord(word) => write!(f, "{word:?} is not in the word list"),
+            GameError::RuleViolation(error) => f.write_str(error),
+            GameError::GameOver => f.write_str("the game is over"),
+            GameError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GameError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GameError {
+    fn from(e: io::Error) -> Self {
+        GameError::Io(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseMatchError(String);
+
+impl fmt::Display for ParseMatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid match: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseMatchError {}
+
+/// Parses the symbols printed by [`Match`]'s `Display` implementation, the
+/// `C`/`M`/`I` abbreviations and the full `correct`/`misplaced`/`incorrect`
+/// names, ignoring case.
+impl FromStr for Match {
+    type Err = ParseMatchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "✓" | "c" | "correct" => Ok(Match::Correct),
+            "~" | "m" | "misplaced" => Ok(Match::Misplaced),
+            "✗" | "i" | "incorrect" => Ok(Match::Incorrect),
+            _ => Err(ParseMatchError(s.to_owned())),
+        }
+    }
 }
 
 #[must_use]
-fn is_valid_word(word: &str) -> bool {
+pub fn is_valid_word(word: &str) -> bool {
     WORDS.contains(&word) || ACCEPTABLE.contains(&word)
 }
 
+/// Compares `guess` against `secret_word` the way Wordle does: letters in the
+/// right spot are [`Correct`](Match::Correct), letters present elsewhere in the
+/// secret word are [`Misplaced`](Match::Misplaced) and the rest are
+/// [`Incorrect`](Match::Incorrect).
+///
+/// Each letter of the secret word can only be matched once, with correct
+/// letters taking precedence over misplaced ones.
+///
+/// # Examples
+///
+/// No repeated letters:
+///
+/// ```
+/// use yawc::game::{check_word, Match::*};
+///
+/// assert_eq!(
+///     check_word("crane", "slate"),
+///     [Incorrect, Incorrect, Correct, Incorrect, Correct],
+/// );
+/// ```
+///
+/// A letter repeated in the guess but present only once in the secret word is
+/// only marked once:
+///
+/// ```
+/// use yawc::game::{check_word, Match::*};
+///
+/// assert_eq!(
+///     check_word("crane", "speed"),
+///     [Incorrect, Incorrect, Misplaced, Incorrect, Incorrect],
+/// );
+/// ```
+///
+/// A letter repeated in the secret word can be matched as many times:
+///
+/// ```
+/// use yawc::game::{check_word, Match::*};
+///
+/// assert_eq!(
+///     check_word("geese", "ember"),
+///     [Misplaced, Incorrect, Incorrect, Misplaced, Incorrect],
+/// );
+/// ```
 #[must_use]
-fn check_word(secret_word: &str, guess: &str) -> [Match; 5] {
-    let mut matches = [Match::Incorrect; 5];
-    let mut secret_word = secret_word.as_bytes().to_vec();
+pub fn check_word(secret_word: &str, guess: &str) -> [Match; 5] {
+    check_letters(secret_word, guess)
+}
+
+/// Same as [`check_word`], for words of `N` letters. Letters are compared as
+/// [`char`]s, so words with accented letters work the same:
+///
+/// ```
+/// use yawc::game::{check_letters, Match::*};
+///
+/// assert_eq!(
+///     check_letters::<6>("réseau", "éperon"),
+///     [Misplaced, Incorrect, Misplaced, Misplaced, Incorrect, Incorrect],
+/// );
+/// // the second `é` isn't marked as there's only one in the secret word
+/// assert_eq!(
+///     check_letters::<5>("élite", "étêté"),
+///     [Correct, Incorrect, Incorrect, Correct, Incorrect],
+/// );
+/// ```
+#[must_use]
+pub fn check_letters<const N: usize>(secret_word: &str, guess: &str) -> [Match; N] {
+    let mut matches = [Match::Incorrect; N];
+    let mut letters: [Option<char>; N] = [None; N];
+    for (slot, c) in letters.iter_mut().zip(secret_word.chars()) {
+        *slot = Some(c);
+    }
     // check for correct letters first
-    for (i, b) in guess.bytes().enumerate() {
-        if secret_word[i] == b {
-            secret_word[i] = 0; // remove this letter so that it will not match again
+    for (i, c) in guess.chars().take(N).enumerate() {
+        if letters[i] == Some(c) {
+            letters[i] = None; // remove this letter so that it will not match again
             matches[i] = Match::Correct;
         }
     }
     // then check for misplaced letters:
-    for (i, c) in guess.bytes().enumerate() {
+    for (i, c) in guess.chars().take(N).enumerate() {
         if matches[i] != Match::Incorrect {
             continue; // skip all correct letters
         }
         // find first occurrence of current letter in the secret word
-        if let Some(j) = secret_word.iter().position(|&b| c == b) {
-            secret_word[j] = 0; // remothe letter from secret word
+        if let Some(j) = letters.iter().position(|&s| s == Some(c)) {
+            letters[j] = None; // remove the letter so that it will not match again
             matches[i] = Match::Misplaced;
         }
     }
 
     matches
 }
+
+/// Same as [`check_word`] for ASCII words, as a `const fn` so that known
+/// results can be worked out at compile time.
+///
+/// ```
+/// use yawc::game::{check_word, check_word_array, Match, Match::*};
+///
+/// const EXPECTED: [Match; 5] = check_word_array(b"crane", b"trace");
+/// assert_eq!(EXPECTED, [Incorrect, Correct, Correct, Misplaced, Correct]);
+/// assert_eq!(EXPECTED, check_word("crane", "trace"));
+/// ```
+#[must_use]
+pub const fn check_word_array(secret: &[u8; 5], guess: &[u8; 5]) -> [Match; 5] {
+    let mut matches = [Match::Incorrect; 5];
+    // letters of the secret word not matched yet
+    let mut left = [true; 5];
+    let mut i = 0;
+    while i < 5 {
+        if guess[i] == secret[i] {
+            left[i] = false;
+            matches[i] = Match::Correct;
+        }
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 5 {
+        if matches!(matches[i], Match::Incorrect) {
+            let mut j = 0;
+            while j < 5 {
+                if left[j] && secret[j] == guess[i] {
+                    left[j] = false;
+                    matches[i] = Match::Misplaced;
+                    break;
+                }
+                j += 1;
+            }
+        }
+        i += 1;
+    }
+    matches
+}
+
+/// Same as [`check_word`], but pairs every letter of `guess` with its match.
+///
+/// ```
+/// use yawc::game::{check_word_detailed, Match::*};
+///
+/// assert_eq!(
+///     check_word_detailed("crane", "react"),
+///     [('r', Misplaced), ('e', Misplaced), ('a', Correct), ('c', Misplaced), ('t', Incorrect)],
+/// );
+/// ```
+#[must_use]
+pub fn check_word_detailed(secret_word: &str, guess: &str) -> Vec<(char, Match)> {
+    guess.chars().zip(check_word(secret_word, guess)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MATCHES: [Match; 3] = [Match::Correct, Match::Misplaced, Match::Incorrect];
+
+    #[test]
+    fn match_symbols_round_trip() {
+        for m in MATCHES {
+            assert_eq!(m.to_string().parse(), Ok(m));
+        }
+    }
+
+    #[test]
+    fn match_abbreviations_round_trip() {
+        for m in MATCHES {
+            let abbreviation = format!("{m:#}");
+            assert_eq!(abbreviation.parse(), Ok(m));
+            assert_eq!(abbreviation.to_lowercase().parse(), Ok(m));
+        }
+    }
+
+    #[test]
+    fn match_names_parse() {
+        for (name, m) in ["correct", "misplaced", "incorrect"]
+            .into_iter()
+            .zip(MATCHES)
+        {
+            assert_eq!(name.parse(), Ok(m));
+            assert_eq!(name.to_uppercase().parse(), Ok(m));
+            assert_eq!(format!("{m:?}").parse(), Ok(m));
+        }
+    }
+
+    mod accents {
+        use super::*;
+        use Match::{Correct as C, Incorrect as I, Misplaced as M};
+
+        #[test]
+        fn accented_letters_match_themselves() {
+            assert_eq!(check_word("école", "école"), [C; 5]);
+            assert_eq!(check_word("forêt", "forêt"), [C; 5]);
+        }
+
+        #[test]
+        fn accents_make_different_letters() {
+            assert_eq!(check_word("élève", "eleve"), [I, C, I, C, C]);
+            assert_eq!(check_word("forêt", "foret"), [C, C, C, I, C]);
+        }
+
+        #[test]
+        fn multi_byte_letters_keep_their_positions() {
+            // `ç` is two bytes, comparing bytes would shift everything after it
+            assert_eq!(check_word("leçon", "çelon"), [M, C, M, C, C]);
+            // and `c` is no more `ç` than `e` is `é`
+            assert_eq!(check_word("garçe", "grâce"), [C, M, I, I, C]);
+        }
+
+        #[test]
+        fn repeated_accented_letter_in_both_words() {
+            assert_eq!(check_word("évité", "étêté"), [C, I, I, C, C]);
+        }
+
+        #[test]
+        fn accented_letter_only_matched_once() {
+            assert_eq!(check_word("bêtes", "êêêêê"), [I, C, I, I, I]);
+            assert_eq!(check_word("rêver", "êtres"), [M, I, M, C, I]);
+        }
+
+        #[test]
+        fn correct_accented_letter_takes_precedence() {
+            // the last `é` is correct, so the first one has nothing left to match
+            assert_eq!(check_word("laité", "éclaé"), [I, I, M, M, C]);
+        }
+    }
+
+    mod redundant {
+        use super::*;
+
+        fn entry(word: &str) -> GuessEntry {
+            GuessEntry::new(word.to_owned(), vec![Match::Incorrect; 5])
+        }
+
+        fn keyboard(marks: &[(&str, Match)]) -> Keyboard {
+            let mut keyboard = Keyboard::default();
+            for &(letters, m) in marks {
+                for c in letters.chars() {
+                    keyboard.mark_letter(c, m);
+                }
+            }
+            keyboard
+        }
+
+        #[test]
+        fn nothing_known() {
+            assert!(!entry("crane").is_redundant(&Keyboard::default()));
+        }
+
+        #[test]
+        fn some_letters_unknown() {
+            let keyboard = keyboard(&[("cra", Match::Correct), ("n", Match::Incorrect)]);
+            assert!(!entry("crane").is_redundant(&keyboard));
+        }
+
+        #[test]
+        fn all_letters_correct_or_incorrect() {
+            let keyboard = keyboard(&[("cr", Match::Correct), ("ane", Match::Incorrect)]);
+            assert!(entry("crane").is_redundant(&keyboard));
+            assert!(entry("caner").is_redundant(&keyboard));
+        }
+
+        #[test]
+        fn all_letters_incorrect() {
+            let keyboard = keyboard(&[("crane", Match::Incorrect)]);
+            assert!(entry("crane").is_redundant(&keyboard));
+        }
+
+        #[test]
+        fn misplaced_letters_still_tell_something() {
+            let keyboard = keyboard(&[("cran", Match::Correct), ("e", Match::Misplaced)]);
+            assert!(!entry("crane").is_redundant(&keyboard));
+        }
+
+        #[test]
+        fn repeated_letters() {
+            let mut keyboard = keyboard(&[("t", Match::Correct), ("a", Match::Incorrect)]);
+            assert!(!entry("tatty").is_redundant(&keyboard));
+            keyboard.mark_letter('y', Match::Incorrect);
+            assert!(entry("tatty").is_redundant(&keyboard));
+        }
+    }
+
+    mod hard_mode {
+        use super::*;
+
+        /// `guess` played against `crane`.
+        fn played(guess: &str) -> GuessEntry {
+            GuessEntry::new(guess.to_owned(), check_word("crane", guess).to_vec())
+        }
+
+        #[test]
+        fn anything_goes_before_the_first_guess() {
+            assert_eq!(hard_mode_check(&[], "zzzzz"), None);
+        }
+
+        #[test]
+        fn found_letters_must_stay_in_place() {
+            // c is in place, r and a are misplaced
+            let guesses = [played("cigar")];
+            assert_eq!(
+                hard_mode_check(&guesses, "scare").as_deref(),
+                Some("Letter 1 must be C, as found in guess 1!")
+            );
+            assert_eq!(hard_mode_check(&guesses, "carry"), None);
+        }
+
+        #[test]
+        fn misplaced_letters_must_be_used() {
+            let guesses = [played("cigar")];
+            assert_eq!(
+                hard_mode_check(&guesses, "cloth").as_deref(),
+                Some("Letter A found in guess 1 must be used!")
+            );
+        }
+
+        #[test]
+        fn ruled_out_letters_may_be_used_again() {
+            // unlike `--superhard`
+            let guesses = [played("cigar")];
+            assert_eq!(hard_mode_check(&guesses, "cairn"), None);
+        }
+
+        #[test]
+        fn every_guess_counts() {
+            let guesses = [played("cigar"), played("crate")];
+            assert_eq!(
+                hard_mode_check(&guesses, "crack").as_deref(),
+                Some("Letter 5 must be E, as found in guess 2!")
+            );
+            assert_eq!(hard_mode_check(&guesses, "crane"), None);
+        }
+
+        #[test]
+        fn game_rejects_guesses_ignoring_hints() -> Result<(), GameError> {
+            let config = Config {
+                hard: true,
+                ..Config::default()
+            };
+            let mut game = Game::headless(config, "crane")?;
+            assert_eq!(game.inject_guess("cigar")?, None);
+            assert!(matches!(
+                game.inject_guess("slate"),
+                Err(GameError::RuleViolation(_))
+            ));
+            assert_eq!(game.inject_guess("crane")?, Some(GameState::Win));
+            Ok(())
+        }
+    }
+
+    mod crossword {
+        use super::*;
+
+        /// A game at the second word of a crossword where letter 3 of the
+        /// first word is letter 4 of the second, with the first found.
+        fn second_word() -> io::Result<Game<'static>> {
+            let config = Config {
+                crossword: "1:3=2:4".parse().ok(),
+                ..Config::default()
+            };
+            let mut game = Game::headless(config, "ideal")?;
+            game.session.push(GameRecord {
+                secret_word: "crane".to_owned(),
+                guesses: vec![GuessEntry::new("crane".to_owned(), vec![Match::Correct; 5])],
+                number: None,
+            });
+            Ok(game)
+        }
+
+        #[test]
+        fn guess_without_the_crossing_letter_is_rejected() -> io::Result<()> {
+            let mut game = second_word()?;
+            let error = game.inject_guess("slate").unwrap_err();
+            assert!(matches!(error, GameError::RuleViolation(_)));
+            assert_eq!(
+                error.to_string(),
+                "Letter 4 must be A, where word 1 crosses it!"
+            );
+            assert!(game.guesses.is_empty());
+            Ok(())
+        }
+
+        #[test]
+        fn guess_with_the_crossing_letter_goes_in() -> Result<(), GameError> {
+            let mut game = second_word()?;
+            assert_eq!(game.inject_guess("steak")?, None);
+            assert_eq!(game.inject_guess("ideal")?, Some(GameState::Win));
+            Ok(())
+        }
+    }
+
+    mod resize {
+        use std::sync::{Arc, Mutex};
+
+        use crossterm::event::{Event, KeyEvent};
+
+        use super::*;
+        use crate::generator::FixedGenerator;
+
+        /// Keeps everything drawn, to look at afterwards.
+        #[derive(Clone, Default)]
+        struct Screen(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Screen {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        impl Screen {
+            fn drawn(&self) -> String {
+                String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+            }
+
+            /// What was drawn since the screen was last cleared.
+            fn since_clear(&self) -> String {
+                let drawn = self.drawn();
+                let clear = drawn
+                    .rfind("\x1b[2J")
+                    .expect("the screen was never cleared");
+                drawn[clear..].to_owned()
+            }
+        }
+
+        fn key(c: char) -> Event {
+            Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+        }
+
+        fn game(screen: &Screen, events: impl IntoIterator<Item = Event>) -> Game<'static> {
+            let term =
+                Terminal::with_fixed_size(80, 30, Box::new(screen.clone())).with_events(events);
+            let config = Config {
+                no_animation: true,
+                ..Config::default()
+            };
+            Game::with_terminal(config, term)
+                .unwrap()
+                .with_generator(Box::new(FixedGenerator("crane")))
+        }
+
+        #[test]
+        fn events_during_an_animation_are_kept_in_order() {
+            let mut game = game(&Screen::default(), [key('a'), key('b'), key('c')]);
+            // used to hand the first key back to itself forever
+            game.poll_resize().unwrap();
+            assert_eq!(game.deferred_events, [key('a'), key('b'), key('c')]);
+            assert!(!game.pending_resize);
+            assert_eq!(game.next_event().unwrap(), key('a'));
+            game.poll_resize().unwrap();
+            assert_eq!(game.deferred_events, [key('b'), key('c')]);
+        }
+
+        #[test]
+        fn resize_is_picked_up_between_other_events() {
+            let events = [key('a'), Event::Resize(100, 40), key('b')];
+            let mut game = game(&Screen::default(), events);
+            game.poll_resize().unwrap();
+            assert!(game.pending_resize);
+            assert_eq!(
+                game.size,
+                TerminalSize {
+                    width: 100,
+                    height: 40
+                }
+            );
+            assert_eq!(game.deferred_events, [key('a'), key('b')]);
+        }
+
+        #[test]
+        fn resize_mid_animation_redraws_the_grid() {
+            let screen = Screen::default();
+            let mut game = game(&screen, [Event::Resize(100, 40)]);
+            game.inject_guess("slate").unwrap();
+            game.inject_guess("crate").unwrap();
+            // the arrows are drawn, then the terminal is resized
+            game.show_match_diff().unwrap();
+            assert!(screen.drawn().contains('▲'));
+
+            assert!(!game.pending_resize);
+            assert_eq!(
+                game.size,
+                TerminalSize {
+                    width: 100,
+                    height: 40
+                }
+            );
+            let redrawn = screen.since_clear();
+            // nothing of the animation is left over
+            assert!(!redrawn.contains('▲') && !redrawn.contains('▼'));
+            // but the whole grid is there, with both guesses
+            assert_eq!(redrawn.matches("┌───┬───┬───┬───┬───┐").count(), 1);
+            assert_eq!(redrawn.matches("└───┴───┴───┴───┴───┘").count(), 1);
+            for letter in ["S", "L", "C", "R", "A", "T", "E"] {
+                assert!(redrawn.contains(letter), "{letter} is missing");
+            }
+        }
+
+        /// A screen too small for the game, whatever the terminal says.
+        struct SmallScreen;
+
+        impl TerminalInfo for SmallScreen {
+            fn size(&self) -> io::Result<(u16, u16)> {
+                Ok((40, 10))
+            }
+        }
+
+        #[test]
+        fn size_comes_from_terminal_info() {
+            let screen = Screen::default();
+            let game = game(&screen, [])
+                .with_terminal_info(Box::new(SmallScreen))
+                .unwrap();
+            assert_eq!(
+                game.size,
+                TerminalSize {
+                    width: 40,
+                    height: 10
+                }
+            );
+            game.benchmark_redraws(1).unwrap();
+            assert!(screen.drawn().contains("Terminal too small"));
+        }
+    }
+
+    #[test]
+    fn classic_grid() {
+        assert_eq!(
+            draw_grid_to_string(5, 6),
+            "\
+┌───┬───┬───┬───┬───┐
+│   │   │   │   │   │
+├───┼───┼───┼───┼───┤
+│   │   │   │   │   │
+├───┼───┼───┼───┼───┤
+│   │   │   │   │   │
+├───┼───┼───┼───┼───┤
+│   │   │   │   │   │
+├───┼───┼───┼───┼───┤
+│   │   │   │   │   │
+├───┼───┼───┼───┼───┤
+│   │   │   │   │   │
+└───┴───┴───┴───┴───┘"
+        );
+    }
+
+    #[test]
+    fn plus_grid() {
+        assert_eq!(
+            draw_grid_to_string(7, 2),
+            "\
+┌───┬───┬───┬───┬───┬───┬───┐
+│   │   │   │   │   │   │   │
+├───┼───┼───┼───┼───┼───┼───┤
+│   │   │   │   │   │   │   │
+└───┴───┴───┴───┴───┴───┴───┘"
+        );
+    }
+
+    #[test]
+    fn grid_has_a_separator_between_each_two_rows() {
+        for max_guesses in 1..=8 {
+            let grid = draw_grid_to_string(5, max_guesses);
+            let rows = |start| grid.lines().filter(|l| l.starts_with(start)).count();
+            assert_eq!(rows('│'), max_guesses);
+            assert_eq!(rows('├'), max_guesses - 1);
+            assert_eq!(rows('┌'), 1);
+            assert_eq!(rows('└'), 1);
+        }
+    }
+
+    #[test]
+    fn single_row_grid() {
+        assert_eq!(draw_grid_to_string(1, 1), "┌───┐\n│   │\n└───┘");
+    }
+
+    #[test]
+    fn grid_lines_are_as_wide_as_the_grid() {
+        for word_len in 1..=8 {
+            let grid = draw_grid_to_string(word_len, 6);
+            assert!(grid.lines().all(|l| l.width() == word_len * 4 + 1));
+        }
+    }
+
+    #[test]
+    fn match_rejects_anything_else() {
+        for s in ["", "x", "✓✓", "correctly", " c"] {
+            assert_eq!(s.parse::<Match>(), Err(ParseMatchError(s.to_owned())));
+        }
+    }
+
+    #[test]
+    fn guess_history_round_trips() {
+        // every pattern of matches a 5-letter guess can get
+        let history: Vec<_> = (0..3u32.pow(5))
+            .map(|mut n| {
+                let matches = (0..5)
+                    .map(|_| {
+                        let m = MATCHES[(n % 3) as usize];
+                        n /= 3;
+                        m
+                    })
+                    .collect();
+                GuessEntry::new("crane".to_owned(), matches)
+            })
+            .collect();
+        for guesses in [&history[..0], &history[..1], &history[..6], &history[..]] {
+            let encoded = serialize_guess_history(guesses);
+            assert_eq!(encoded.len(), guesses.len() * 8);
+            assert!(encoded.bytes().all(|b| BASE64_URL.contains(&b)));
+            assert_eq!(
+                deserialize_guess_history(&encoded).as_deref(),
+                Some(guesses)
+            );
+        }
+    }
+
+    #[test]
+    fn share_text_links_to_the_history() {
+        let history = [
+            GuessEntry::new("slate".to_owned(), vec![Match::Misplaced; 5]),
+            GuessEntry::new("crane".to_owned(), vec![Match::Correct; 5]),
+        ];
+        let text = share_text(&history, Some(1234), MAX_GUESSES, EmojiStyle::Wordle);
+        let link = text.lines().last().unwrap();
+        let encoded = link.strip_prefix(SHARE_VIEWER_URL).unwrap();
+        assert_eq!(
+            deserialize_guess_history(encoded.strip_prefix("?h=").unwrap()).as_deref(),
+            Some(&history[..])
+        );
+    }
+
+    #[test]
+    fn suggestion_is_cancelled_when_the_game_ends() {
+        let mut game = Game::headless(Config::default(), "crane").unwrap();
+        game.inject_guess("slate").unwrap();
+        let cancel = Arc::clone(&game.suggestion_cancel);
+        assert!(game.pending_suggestion.is_some());
+        assert!(!cancel.load(Ordering::Relaxed));
+
+        game.inject_guess("crane").unwrap();
+        assert!(game.pending_suggestion.is_none());
+        assert!(cancel.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn guess_history_rejects_anything_else() {
+        let encoded = serialize_guess_history(&[GuessEntry::new(
+            "crane".to_owned(),
+            vec![Match::Correct; 5],
+        )]);
+        for s in [
+            &encoded[..7],
+            &encoded[..4],
+            "slate,crane",
+            "c2xhdGX_",
+            "QUJDREUA",
+        ] {
+            assert_eq!(deserialize_guess_history(s), None, "{s}");
+        }
+    }
+
+    #[test]
+    fn schema_descriptions_are_the_doc_comments() {
+        let schema = GameRecord::json_schema();
+        let entry = &schema["$defs"]["GuessEntry"];
+        assert_eq!(
+            schema["properties"]["secret_word"]["description"],
+            "The word that had to be guessed."
+        );
+        assert_eq!(
+            entry["properties"]["quality"]["description"],
+            "How much the guess revealed: 3 points for every correct letter, 2 for every \
+             misplaced one and 1 for every incorrect one, as match_quality_score counts them."
+        );
+        for object in [&schema, entry] {
+            let properties = object["properties"].as_object().unwrap();
+            for (name, property) in properties {
+                let description = property["description"].as_str().unwrap();
+                assert!(!description.is_empty(), "{name} has no doc comment");
+            }
+        }
+        assert_eq!(
+            schema["$defs"]["Match"]["enum"],
+            json!(["Correct", "Misplaced", "Incorrect"])
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{config::Config, puzzle::PuzzleGenerator};
+
+use rand::{
+    rngs::{StdRng, ThreadRng},
+    seq::SliceRandom,
+    thread_rng, SeedableRng,
+};
+
+/// Source of the secret word of each game.
+pub trait SecretWordGenerator {
+    fn next_word(&mut self) -> &'static str;
+
+    /// Message to show while the word last given by
+    /// [`next_word`](Self::next_word) is being guessed.
+    fn intro_message(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The generator asked for on the command line: the puzzles of
+/// `--puzzle-set`, the word of the day with `--daily`, seeded with `--seed`,
+/// random otherwise.
+#[must_use]
+pub fn for_config(config: &Config) -> Box<dyn SecretWordGenerator> {
+    if let Some(puzzles) = config
+        .puzzles
+        .clone()
+        .and_then(|set| PuzzleGenerator::new(set, config.seed))
+    {
+        return Box::new(puzzles);
+    }
+    let words = config.secret_words();
+    match config.seed {
+        _ if config.daily => Box::new(DateBasedGenerator::today(words)),
+        Some(seed) => Box::new(SeededGenerator::new(words, seed)),
+        None => Box::new(RandomGenerator::new(words)),
+    }
+}
+
+/// Picks every word at random.
+pub struct RandomGenerator {
+    words: &'static [&'static str],
+    rng: ThreadRng,
+}
+
+impl RandomGenerator {
+    #[must_use]
+    pub fn new(words: &'static [&'static str]) -> Self {
+        Self {
+            words,
+            rng: thread_rng(),
+        }
+    }
+}
+
+impl SecretWordGenerator for RandomGenerator {
+    fn next_word(&mut self) -> &'static str {
+        self.words.choose(&mut self.rng).unwrap()
+    }
+}
+
+/// Picks words at random, the same ones in the same order for a given seed.
+pub struct SeededGenerator {
+    words: &'static [&'static str],
+    rng: StdRng,
+}
+
+impl SeededGenerator {
+    #[must_use]
+    pub fn new(words: &'static [&'static str], seed: u64) -> Self {
+        Self {
+            words,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl SecretWordGenerator for SeededGenerator {
+    fn next_word(&mut self) -> &'static str {
+        self.words.choose(&mut self.rng).unwrap()
+    }
+}
+
+/// Word of the day: everyone gets the same word on the same day, and each
+/// game after the first moves on to the next day's word.
+pub struct DateBasedGenerator {
+    words: &'static [&'static str],
+    days_since_epoch: u32,
+}
+
+impl DateBasedGenerator {
+    #[must_use]
+    pub fn new(words: &'static [&'static str], days_since_epoch: u32) -> Self {
+        Self {
+            words,
+            days_since_epoch,
+        }
+    }
+
+    /// Starts from today's word, going by UTC.
+    #[must_use]
+    pub fn today(words: &'static [&'static str]) -> Self {
+        Self::new(words, today())
+    }
+}
+
+/// Days since the Unix epoch, in UTC.
+fn today() -> u32 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86400);
+    days.try_into().unwrap_or(u32::MAX)
+}
+
+/// Day of the first Wordle, 2021-06-19, in days since the Unix epoch.
+const WORDLE_EPOCH: u32 = 18_797;
+
+/// Number of the Wordle of the day `days` days after the Unix epoch, `0`
+/// for the first one.
+///
+/// ```
+/// use yawc::generator::wordle_number;
+///
+/// // 2022-01-01
+/// assert_eq!(wordle_number(18_993), 196);
+/// ```
+#[must_use]
+pub fn wordle_number(days: u32) -> u32 {
+    days.saturating_sub(WORDLE_EPOCH)
+}
+
+/// Number of today's Wordle, for `--daily`.
+#[must_use]
+pub fn todays_number() -> u32 {
+    wordle_number(today())
+}
+
+impl SecretWordGenerator for DateBasedGenerator {
+    fn next_word(&mut self) -> &'static str {
+        let word = self.words[self.days_since_epoch as usize % self.words.len()];
+        self.days_since_epoch = self.days_since_epoch.wrapping_add(1);
+        word
+    }
+}
+
+/// Always gives the same word.
+pub struct FixedGenerator(pub &'static str);
+
+impl SecretWordGenerator for FixedGenerator {
+    fn next_word(&mut self) -> &'static str {
+        self.0
+    }
+}
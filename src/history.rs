@@ -0,0 +1,148 @@
+use crossterm::event::KeyCode;
+
+use crate::{
+    stats::{ScoreRecord, MAX_GUESSES},
+    theme::EmojiStyle,
+};
+
+/// Rows moved by `PgUp` and `PgDn`.
+const PAGE: usize = 10;
+
+/// List of the games in the scores file, newest first, opened with `h` at
+/// the end of a game. The secret words stay hidden until `r` is pressed, and
+/// `Enter` shows the guesses of the selected game.
+///
+/// ```
+/// use crossterm::event::KeyCode;
+/// use yawc::{
+///     game::{GuessEntry, Match::*},
+///     history::HistoryViewer,
+///     stats::ScoreRecord,
+/// };
+///
+/// let record = |word: &str, timestamp| ScoreRecord {
+///     timestamp,
+///     word: word.to_owned(),
+///     guesses: Some(1),
+///     score: 600,
+///     pangram: false,
+///     history: vec![GuessEntry::new(word.to_owned(), vec![Correct; 5])],
+///     chain: None,
+///     revealed: false,
+///     number: None,
+/// };
+/// let mut viewer = HistoryViewer::new(vec![record("crane", 0), record("slate", 86400)]);
+///
+/// let lines = viewer.lines(10);
+/// assert_eq!(lines[0], "▶ 1970-01-02  *****  1/6  won");
+/// assert_eq!(lines[1], "  1970-01-01  *****  1/6  won");
+///
+/// assert!(viewer.handle_key(KeyCode::Down));
+/// assert!(viewer.handle_key(KeyCode::Char('r')));
+/// assert!(viewer.handle_key(KeyCode::Enter));
+/// assert_eq!(
+///     viewer.lines(10),
+///     [
+///         "  1970-01-02  slate  1/6  won",
+///         "▶ 1970-01-01  crane  1/6  won",
+///         "      🟩🟩🟩🟩🟩 CRANE",
+///     ]
+/// );
+/// assert!(!viewer.handle_key(KeyCode::Esc));
+/// ```
+pub struct HistoryViewer {
+    records: Vec<ScoreRecord>,
+    selected: usize,
+    reveal: bool,
+    /// Whether the guesses of the selected game are shown.
+    expanded: bool,
+}
+
+impl HistoryViewer {
+    #[must_use]
+    pub fn new(mut records: Vec<ScoreRecord>) -> Self {
+        records.reverse();
+        Self {
+            records,
+            selected: 0,
+            reveal: false,
+            expanded: false,
+        }
+    }
+
+    /// Columns taken by a game of 5-letter words.
+    pub const WIDTH: u16 = 30;
+
+    /// Moves around the list for `key`. Returns `false` if it closes the
+    /// viewer.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let last = self.records.len().saturating_sub(1);
+        let selected = self.selected;
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => return false,
+            KeyCode::Up => self.selected = selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (selected + 1).min(last),
+            KeyCode::PageUp => self.selected = selected.saturating_sub(PAGE),
+            KeyCode::PageDown => self.selected = (selected + PAGE).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Enter => self.expanded = !self.expanded,
+            KeyCode::Char('r') => self.reveal = !self.reveal,
+            _ => {}
+        }
+        true
+    }
+
+    /// Up to `rows` lines of the list, scrolled to keep the selected game in
+    /// the upper part.
+    #[must_use]
+    pub fn lines(&self, rows: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut selected_line = 0;
+        for (i, record) in self.records.iter().enumerate() {
+            let word = if self.reveal {
+                record.word.clone()
+            } else {
+                "*".repeat(record.word.chars().count())
+            };
+            let result = match record.guesses {
+                Some(n) => format!("{n}/{MAX_GUESSES}  won"),
+                None => format!("X/{MAX_GUESSES}  lost"),
+            };
+            let marker = if i == self.selected { '▶' } else { ' ' };
+            if i == self.selected {
+                selected_line = lines.len();
+            }
+            lines.push(format!("{marker} {}  {word}  {result}", record.date()));
+            if i == self.selected && self.expanded {
+                lines.extend(self.guess_lines(record));
+            }
+        }
+        let start = selected_line.saturating_sub(rows / 3);
+        lines.into_iter().skip(start).take(rows).collect()
+    }
+
+    /// The guesses of `record`, as squares followed by the word if the words
+    /// are revealed.
+    fn guess_lines(&self, record: &ScoreRecord) -> Vec<String> {
+        if record.history.is_empty() {
+            return vec!["      (guesses not recorded)".to_owned()];
+        }
+        record
+            .history
+            .iter()
+            .map(|g| {
+                let squares: String = g
+                    .matches
+                    .iter()
+                    .map(|&m| EmojiStyle::Wordle.symbol(m))
+                    .collect();
+                if self.reveal {
+                    format!("      {squares} {}", g.word.to_uppercase())
+                } else {
+                    format!("      {squares}")
+                }
+            })
+            .collect()
+    }
+}
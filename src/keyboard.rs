@@ -1,28 +1,61 @@
 use std::fmt;
 
+use clap::ValueEnum;
 use crossterm::{
     cursor::{self},
     style::{self, Stylize},
 };
 
-use crate::game::Match;
+use crate::{constraints::ConstraintSet, game::Match, locale::Locale, theme::Theme};
 
-pub(crate) struct Keyboard(Vec<(char, Option<Match>)>);
+/// Where the on-screen keyboard goes, set with `--keyboard-position`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum KeyboardPosition {
+    /// Above the grid, which moves down to make room for it.
+    Top,
+    /// Below the grid, near the bottom of the screen.
+    #[default]
+    Bottom,
+}
+
+/// The on-screen keyboard, remembering the best match seen for each letter.
+pub struct Keyboard {
+    keys: Vec<(char, Option<Match>)>,
+    /// Rows of keys, top to bottom.
+    layout: &'static [&'static str],
+    wide: bool,
+    /// Flipped left to right, so that rows read `poiuytrewq` and so on.
+    mirror: bool,
+    pub(crate) theme: Theme,
+}
 
 impl Keyboard {
-    fn new() -> Self {
-        let letters = "qwertyuiopasdfghjklzxcvbnm";
-        let mut keyboard = Vec::with_capacity(26);
-        for c in letters.chars() {
-            keyboard.push((c, None));
+    pub(crate) fn new(
+        layout: &'static [&'static str],
+        wide: bool,
+        mirror: bool,
+        theme: Theme,
+    ) -> Self {
+        let keys = layout
+            .iter()
+            .flat_map(|row| row.chars())
+            .map(|c| (c, None))
+            .collect();
+        Self {
+            keys,
+            layout,
+            wide,
+            mirror,
+            theme,
         }
-        Self(keyboard)
     }
 }
 
 impl Keyboard {
-    pub(crate) fn mark_letter(&mut self, letter: char, mark: Match) {
-        if let Some(m) = self.0.iter_mut().find_map(|(c, m)| {
+    /// Marks the key of `letter` as `mark`, unless it's already known to be
+    /// correct.
+    pub fn mark_letter(&mut self, letter: char, mark: Match) {
+        if let Some(m) = self.keys.iter_mut().find_map(|(c, m)| {
             if *c == letter && !matches!(m, Some(Match::Correct)) {
                 Some(m)
             } else {
@@ -32,82 +65,337 @@ impl Keyboard {
             *m = Some(mark);
         }
     }
+
+    /// How `c` was marked by the guesses so far, [`None`] if it wasn't used
+    /// yet or isn't a letter.
+    #[must_use]
+    pub fn letter_state(&self, c: char) -> Option<Match> {
+        self.keys
+            .iter()
+            .find(|(k, _)| *k == c.to_ascii_lowercase())
+            .and_then(|(_, m)| *m)
+    }
+
+    /// Letters marked as `state`, or not marked yet if it's [`None`], in the
+    /// order of the keys.
+    ///
+    /// ```
+    /// use yawc::{game::Match::*, keyboard::Keyboard};
+    ///
+    /// let mut keyboard = Keyboard::default();
+    /// keyboard.mark_letter('a', Incorrect);
+    /// keyboard.mark_letter('n', Incorrect);
+    /// assert_eq!(keyboard.iter_by_state(Some(Incorrect)).collect::<String>(), "an");
+    /// ```
+    pub fn iter_by_state(&self, state: Option<Match>) -> impl Iterator<Item = char> + '_ {
+        self.keys
+            .iter()
+            .filter_map(move |&(c, m)| (m == state).then_some(c))
+    }
+
+    /// Letters that weren't marked yet, in alphabetical order.
+    pub(crate) fn untried_letters(&self) -> Vec<char> {
+        let mut letters: Vec<_> = self.iter_by_state(None).collect();
+        letters.sort_unstable();
+        letters
+    }
+
+    /// Clears the marks of all letters, keeping the allocation.
+    pub(crate) fn reset(&mut self) {
+        for (_, m) in &mut self.keys {
+            *m = None;
+        }
+    }
+
+    /// Width of a single key including one of its borders.
+    fn key_width(&self) -> usize {
+        if self.wide {
+            6
+        } else {
+            4
+        }
+    }
+
+    /// Columns spanned by the `row`th row of keys, each row being shifted half
+    /// a key to the right of the previous one, or to the left when mirrored.
+    fn row_span(&self, row: usize) -> (usize, usize) {
+        let (start, end) = self.unmirrored_row_span(row);
+        if self.mirror {
+            let width = (0..self.layout.len())
+                .map(|row| self.unmirrored_row_span(row).1)
+                .max()
+                .unwrap_or(0);
+            (width - end, width - start)
+        } else {
+            (start, end)
+        }
+    }
+
+    fn unmirrored_row_span(&self, row: usize) -> (usize, usize) {
+        let start = row * self.key_width() / 2;
+        (
+            start,
+            start + self.layout[row].chars().count() * self.key_width(),
+        )
+    }
+
+    /// Builds the border line between two rows of keys, either of which may
+    /// be missing for the top and bottom borders of the keyboard.
+    fn border(&self, above: Option<usize>, below: Option<usize>) -> String {
+        let spans = [above, below].map(|row| row.map(|row| self.row_span(row)));
+        let end = spans.iter().flatten().map(|&(_, b)| b).max().unwrap_or(0);
+        let is_border = |span: Option<(usize, usize)>, x: usize| {
+            span.is_some_and(|(a, b)| {
+                (a..=b).contains(&x) && (x - a).is_multiple_of(self.key_width())
+            })
+        };
+
+        (0..=end)
+            .map(|x| {
+                let up = is_border(spans[0], x);
+                let down = is_border(spans[1], x);
+                let left = spans.iter().flatten().any(|&(a, b)| a < x && x <= b);
+                let right = spans.iter().flatten().any(|&(a, b)| a <= x && x < b);
+                match (up, down, left, right) {
+                    (false, true, false, true) => '┌',
+                    (false, true, true, false) => '┐',
+                    (true, false, false, true) => '└',
+                    (true, false, true, false) => '┘',
+                    (false, true, true, true) => '┬',
+                    (true, false, true, true) => '┴',
+                    (true, true, false, true) => '├',
+                    (true, true, true, false) => '┤',
+                    (true, true, true, true) => '┼',
+                    (true, true, false, false) => '│',
+                    (false, false, true, _) | (false, false, _, true) => '─',
+                    _ => ' ',
+                }
+            })
+            .collect()
+    }
+}
+
+/// Total width of the keyboard display in columns.
+#[must_use]
+pub fn keyboard_width(layout: &'static [&'static str], wide: bool) -> u16 {
+    let keyboard = Keyboard::new(layout, wide, false, Theme::default());
+    let end = (0..layout.len())
+        .map(|row| keyboard.row_span(row).1)
+        .max()
+        .unwrap_or(0);
+    end as u16 + 1
 }
 
 impl Default for Keyboard {
     fn default() -> Self {
-        Self::new()
+        Self::new(Locale::En.layout(), false, false, Theme::default())
     }
 }
 
 impl fmt::Display for Keyboard {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_keys(f, |_| Vec::new())
+    }
+}
+
+impl Keyboard {
+    /// Draws the keys, each followed by the positions `ruled_out` gives for
+    /// its letter as subscripts, as many as fit.
+    fn write_keys(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ruled_out: impl Fn(char) -> Vec<usize>,
+    ) -> fmt::Result {
+        let mut keys = self.keys.iter();
+        for (row, len) in self.layout.iter().map(|r| r.chars().count()).enumerate() {
+            let above = row.checked_sub(1);
+            write!(
+                f,
+                "{}{}{}{}{}{}",
+                cursor::SavePosition,
+                style::Print(self.border(above, Some(row))),
+                cursor::RestorePosition,
+                cursor::MoveDown(1),
+                cursor::SavePosition,
+                style::Print(format!("{:>1$}", "│", self.row_span(row).0 + 1)),
+            )?;
+            let row: Vec<_> = keys.by_ref().take(len).collect();
+            if self.mirror {
+                print_row(f, row.into_iter().rev(), self.wide, &self.theme, &ruled_out)?;
+            } else {
+                print_row(f, row.into_iter(), self.wide, &self.theme, &ruled_out)?;
+            }
+            write!(f, "{}{}", cursor::RestorePosition, cursor::MoveDown(1),)?;
+        }
         write!(
             f,
-            "{}{}{}{}{}{}",
-            cursor::SavePosition,
-            style::Print("┌───┬───┬───┬───┬───┬───┬───┬───┬───┬───┐"),
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-            cursor::SavePosition,
-            style::Print("│"),
-        )?;
-        print_row(f, self.0.iter().take(10))?;
-        write!(
-            f,
-            "{}{}{}{}{}{}{}{}",
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-            cursor::SavePosition,
-            style::Print("└─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┘"),
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-            cursor::SavePosition,
-            style::Print("  │"),
-        )?;
-        print_row(f, self.0.iter().skip(10).take(9))?;
-        write!(
-            f,
-            "{}{}{}{}{}{}{}{}",
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-            cursor::SavePosition,
-            style::Print("  └─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴─┬─┴───┘"),
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
-            cursor::SavePosition,
-            style::Print("    │"),
-        )?;
-        print_row(f, self.0.iter().skip(10).skip(9))?;
-        write!(
-            f,
-            "{}{}{}{}",
-            cursor::RestorePosition,
-            cursor::MoveDown(1),
+            "{}{}",
             cursor::SavePosition,
-            style::Print("    └───┴───┴───┴───┴───┴───┴───┘")
+            style::Print(self.border(Some(self.layout.len() - 1), None)),
         )?;
         Ok(())
     }
 }
 
+/// The keyboard with the positions each letter is known not to be at, as
+/// [`infer_constraints`](crate::constraints::infer_constraints) works them
+/// out, written as subscripts next to the letter: `A₁₃` is in the word, but
+/// neither first nor third. Drawn with `--smart-keyboard`.
+pub struct SmartKeyboard<'a> {
+    keyboard: &'a Keyboard,
+    constraints: &'a ConstraintSet,
+}
+
+impl<'a> SmartKeyboard<'a> {
+    #[must_use]
+    pub fn new(keyboard: &'a Keyboard, constraints: &'a ConstraintSet) -> Self {
+        Self {
+            keyboard,
+            constraints,
+        }
+    }
+}
+
+impl fmt::Display for SmartKeyboard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.keyboard
+            .write_keys(f, |c| self.constraints.ruled_out(c))
+    }
+}
+
 fn print_row<'a>(
     f: &mut fmt::Formatter,
     row: impl Iterator<Item = &'a (char, Option<Match>)>,
+    wide: bool,
+    theme: &Theme,
+    ruled_out: impl Fn(char) -> Vec<usize>,
 ) -> Result<(), fmt::Error> {
-    for (c, m) in row {
+    // columns inside a key
+    let inner = if wide { 5 } else { 3 };
+    for &(c, m) in row {
+        let subscripts: String = ruled_out(c)
+            .into_iter()
+            .take(inner - 1)
+            .map(|i| SUBSCRIPTS[(i + 1) % 10])
+            .collect();
+        let left = (inner - 1 - subscripts.chars().count()) / 2;
+        let right = inner - 1 - subscripts.chars().count() - left;
         write!(
             f,
-            " {} │",
-            m.map_or_else(
-                || c.to_ascii_uppercase().white().bold(),
-                |m| match m {
-                    Match::Correct => c.to_ascii_uppercase().black().on_green(),
-                    Match::Misplaced => c.to_ascii_uppercase().black().on_yellow(),
-                    Match::Incorrect => c.to_ascii_uppercase().dark_grey().crossed_out().dim(),
-                }
-            )
+            "{:left$}{}{}{:right$}│",
+            "",
+            theme.key(c, m),
+            subscripts.dark_grey(),
+            ""
         )?;
     }
     Ok(())
 }
+
+const SUBSCRIPTS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn letter_state_of_marked_letters() {
+        let mut keyboard = Keyboard::default();
+        assert_eq!(keyboard.letter_state('c'), None);
+        keyboard.mark_letter('c', Match::Misplaced);
+        assert_eq!(keyboard.letter_state('c'), Some(Match::Misplaced));
+        keyboard.mark_letter('c', Match::Correct);
+        assert_eq!(keyboard.letter_state('c'), Some(Match::Correct));
+        // correct stays correct
+        keyboard.mark_letter('c', Match::Incorrect);
+        assert_eq!(keyboard.letter_state('c'), Some(Match::Correct));
+        // case doesn't matter
+        assert_eq!(keyboard.letter_state('C'), Some(Match::Correct));
+        assert_eq!(keyboard.letter_state('r'), None);
+    }
+
+    #[test]
+    fn letter_state_of_non_letters() {
+        let keyboard = Keyboard::default();
+        assert_eq!(keyboard.letter_state('1'), None);
+        assert_eq!(keyboard.letter_state(' '), None);
+    }
+
+    #[test]
+    fn reset_clears_every_letter() {
+        let mut keyboard = Keyboard::default();
+        for (c, m) in "crane".chars().zip(
+            [Match::Correct, Match::Misplaced, Match::Incorrect]
+                .iter()
+                .cycle(),
+        ) {
+            keyboard.mark_letter(c, *m);
+        }
+        keyboard.reset();
+        assert!(('a'..='z').all(|c| keyboard.letter_state(c).is_none()));
+        assert_eq!(keyboard.iter_by_state(None).count(), 26);
+    }
+
+    #[test]
+    fn reset_keeps_the_allocation() {
+        let mut keyboard = Keyboard::default();
+        keyboard.mark_letter('a', Match::Correct);
+        let keys = keyboard.keys.as_ptr();
+        keyboard.reset();
+        assert_eq!(keyboard.keys.as_ptr(), keys);
+    }
+
+    #[test]
+    fn mark_letter_after_reset() {
+        let mut keyboard = Keyboard::default();
+        keyboard.mark_letter('a', Match::Correct);
+        keyboard.reset();
+        // no longer correct, so it can be marked as anything
+        keyboard.mark_letter('a', Match::Incorrect);
+        assert_eq!(keyboard.letter_state('a'), Some(Match::Incorrect));
+        keyboard.mark_letter('b', Match::Misplaced);
+        assert_eq!(keyboard.letter_state('b'), Some(Match::Misplaced));
+        assert_eq!(keyboard.iter_by_state(None).count(), 24);
+    }
+
+    #[test]
+    fn iter_by_state_of_each_state() {
+        let mut keyboard = Keyboard::default();
+        assert_eq!(keyboard.iter_by_state(None).count(), 26);
+
+        keyboard.mark_letter('c', Match::Correct);
+        keyboard.mark_letter('r', Match::Misplaced);
+        keyboard.mark_letter('a', Match::Incorrect);
+        keyboard.mark_letter('n', Match::Incorrect);
+        let letters = |state| keyboard.iter_by_state(state).collect::<String>();
+        assert_eq!(letters(Some(Match::Correct)), "c");
+        assert_eq!(letters(Some(Match::Misplaced)), "r");
+        // keyboard order
+        assert_eq!(letters(Some(Match::Incorrect)), "an");
+        assert_eq!(keyboard.iter_by_state(None).count(), 22);
+        assert!(keyboard.iter_by_state(None).all(|c| !"cran".contains(c)));
+    }
+
+    #[test]
+    fn iter_by_state_gives_every_letter_once() {
+        let mut keyboard = Keyboard::default();
+        let marks = [Match::Correct, Match::Misplaced, Match::Incorrect];
+        for (c, m) in ('a'..='z').zip(marks.iter().cycle()) {
+            keyboard.mark_letter(c, *m);
+        }
+        let states = [
+            None,
+            Some(Match::Correct),
+            Some(Match::Misplaced),
+            Some(Match::Incorrect),
+        ];
+        let letters: Vec<_> = states
+            .iter()
+            .flat_map(|&s| keyboard.iter_by_state(s))
+            .collect();
+        assert_eq!(letters.len(), 26);
+        assert_eq!(letters.iter().collect::<HashSet<_>>().len(), 26);
+    }
+}
@@ -1,4 +1,40 @@
+pub mod allocations;
+pub mod analysis;
+pub mod animation;
+pub mod capability;
+pub mod clue;
+pub mod config;
+pub mod constraints;
+pub mod countdown;
+pub mod crash;
+pub mod crossword;
+pub mod dictionary;
+#[cfg(feature = "download")]
+pub mod download;
+pub mod filter;
+pub mod frequency_grid;
 pub mod game;
+pub mod generator;
+pub mod history;
 pub mod keyboard;
+pub mod loader;
+pub mod locale;
+pub mod logger;
+pub mod mode;
+pub mod puzzle;
+#[cfg(feature = "qr")]
+pub mod qr;
 pub mod raw;
+pub mod replay;
+pub mod solver;
+pub mod sound;
+pub mod stats;
+pub mod status;
+#[cfg(feature = "svg")]
+pub mod svg;
+pub mod theme;
+pub mod tips;
+pub mod tournament;
+pub mod variant;
+pub mod word_graph;
 pub mod words;
@@ -0,0 +1,172 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    thread,
+};
+
+use crossbeam_channel::RecvError;
+use crossterm::{cursor, execute, style, terminal};
+
+use crate::{
+    filter::letter_index,
+    raw::{Terminal, TerminalInfo},
+};
+
+/// Lines read between two progress updates.
+const UPDATE_EVERY: usize = 1000;
+
+/// Width of the progress bar of the loading screen, in columns.
+const BAR_WIDTH: usize = 40;
+
+/// How far reading a word list has got, in lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoadProgress {
+    pub loaded: usize,
+    pub total: usize,
+}
+
+/// Reads the words of `contents`, one per line, telling `progress` how far
+/// it got as it goes. Blank lines are skipped. Every word list given to yawc,
+/// from a file or downloaded, goes through this.
+///
+/// ```
+/// use yawc::loader::{parse_word_list, LoadProgress};
+///
+/// let mut last = None;
+/// let words = parse_word_list("crane\n\nslate\n", 5, |p| last = Some(p)).unwrap();
+/// assert_eq!(words, ["crane", "slate"]);
+/// assert_eq!(last, Some(LoadProgress { loaded: 3, total: 3 }));
+///
+/// assert!(parse_word_list("crane\nslates\n", 5, |_| {}).is_err());
+/// assert!(parse_word_list("Crane\n", 5, |_| {}).is_err());
+/// assert!(parse_word_list("\n", 5, |_| {}).is_err());
+///
+/// // accented letters are fine, but not whole other alphabets
+/// assert_eq!(parse_word_list("éclat\n", 5, |_| {}).unwrap(), ["éclat"]);
+/// let err = parse_word_list("crane\nслово\n", 5, |_| {}).unwrap_err();
+/// assert_eq!(err.to_string(), "line 2: \"слово\" has 'с', which isn't a letter yawc knows");
+/// ```
+///
+/// # Errors
+///
+/// If a line isn't a word of `word_len` lowercase letters, has a letter yawc
+/// can't keep track of, like those of the Cyrillic alphabet, or there are no
+/// words at all.
+pub fn parse_word_list(
+    contents: &str,
+    word_len: usize,
+    mut progress: impl FnMut(LoadProgress),
+) -> io::Result<Vec<String>> {
+    let total = contents.lines().count();
+    let mut words = Vec::new();
+    for (n, line) in contents.lines().enumerate() {
+        let loaded = n + 1;
+        if loaded % UPDATE_EVERY == 0 || loaded == total {
+            progress(LoadProgress { loaded, total });
+        }
+        let word = line.trim();
+        if word.is_empty() {
+            continue;
+        }
+        if word.chars().count() != word_len
+            || !word.chars().all(|c| c.is_alphabetic() && c.is_lowercase())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {loaded}: {word:?} is not a word of {word_len} lowercase letters"),
+            ));
+        }
+        // the filter of possible words would drop every word with such a letter
+        if let Some(c) = word.chars().find(|&c| letter_index(c).is_none()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {loaded}: {word:?} has {c:?}, which isn't a letter yawc knows"),
+            ));
+        }
+        words.push(word.to_owned());
+    }
+    if words.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the list has no words",
+        ));
+    }
+    Ok(words)
+}
+
+/// Loads the word list at `path` for `--word-list` in a background thread,
+/// showing how far it got on a loading screen meanwhile with
+/// `loading_screen`, and if there's a terminal to show it on.
+///
+/// # Errors
+///
+/// If the file can't be read or isn't a valid list, see [`parse_word_list`].
+pub fn load_word_file(
+    path: &Path,
+    word_len: usize,
+    loading_screen: bool,
+) -> io::Result<&'static [&'static str]> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let file = path.to_owned();
+    let loader = thread::spawn(move || {
+        // nobody may be watching anymore, which is no reason to stop
+        parse_word_list(&fs::read_to_string(file)?, word_len, |p| {
+            let _ = tx.send(p);
+        })
+    });
+
+    let mut term = loading_screen.then(Terminal::new).and_then(Result::ok);
+    let mut progress = LoadProgress {
+        loaded: 0,
+        total: 0,
+    };
+    // ends once the loader is done and drops its sender
+    loop {
+        if let Some(term) = &mut term {
+            draw_loading_screen(term, path, progress)?;
+        }
+        match rx.recv() {
+            Ok(p) => progress = p,
+            Err(RecvError) => break,
+        }
+    }
+    drop(term);
+
+    let words = loader
+        .join()
+        .map_err(|_| io::Error::other("loading the word list panicked"))??;
+    // the words are used for the rest of the program, like the built-in ones
+    let words: Vec<&'static str> = words
+        .into_iter()
+        .map(|w| &*Box::leak(w.into_boxed_str()))
+        .collect();
+    Ok(Vec::leak(words))
+}
+
+fn draw_loading_screen(term: &mut Terminal, path: &Path, progress: LoadProgress) -> io::Result<()> {
+    let (width, height) = term.size()?;
+    let filled = (progress.loaded * BAR_WIDTH)
+        .checked_div(progress.total)
+        .unwrap_or(0);
+    let lines = [
+        format!("Loading {}", path.display()),
+        format!(
+            "{}{} {}/{}",
+            "█".repeat(filled),
+            "░".repeat(BAR_WIDTH - filled),
+            progress.loaded,
+            progress.total
+        ),
+    ];
+    execute!(term, terminal::Clear(terminal::ClearType::All))?;
+    for (row, line) in (height / 2..).zip(lines) {
+        let len = u16::try_from(line.chars().count()).unwrap_or(width);
+        execute!(
+            term,
+            cursor::MoveTo((width / 2).saturating_sub(len / 2), row),
+            style::Print(line)
+        )?;
+    }
+    term.flush()
+}
@@ -0,0 +1,91 @@
+use std::{fmt, sync::OnceLock};
+
+use clap::ValueEnum;
+
+use crate::words::{ACCEPTABLE, WORDS};
+
+/// Language of the word lists and keyboard layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+    Es,
+    De,
+}
+
+impl Locale {
+    #[must_use]
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::Es => "es",
+            Locale::De => "de",
+        }
+    }
+
+    /// Rows of the keyboard, top to bottom: AZERTY in French, QWERTZ in
+    /// German and QWERTY elsewhere.
+    #[must_use]
+    pub fn layout(self) -> &'static [&'static str] {
+        match self {
+            Locale::En => &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            Locale::Fr => &["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            Locale::Es => &["qwertyuiop", "asdfghjklñ", "zxcvbnm"],
+            Locale::De => &["qwertzuiopü", "asdfghjklöä", "yxcvbnmß"],
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+fn split_list(list: &'static str) -> Vec<&'static str> {
+    list.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// Secret and acceptable words for `locale`. The bundled lists of other
+/// languages are small enough that every word in them can be the secret one,
+/// so both are the same. The French and Spanish words are written without
+/// accents, apart from `ñ`, as Wordle in those languages is played. Unknown
+/// locales get the English lists.
+#[must_use]
+pub fn bundled_word_lists(locale: &str) -> (&'static [&'static str], &'static [&'static str]) {
+    static FR: OnceLock<Vec<&str>> = OnceLock::new();
+    static ES: OnceLock<Vec<&str>> = OnceLock::new();
+    static DE: OnceLock<Vec<&str>> = OnceLock::new();
+
+    let words: &'static [&'static str] = match locale {
+        "fr" => FR.get_or_init(|| split_list(include_str!("../assets/words_fr.txt"))),
+        "es" => ES.get_or_init(|| split_list(include_str!("../assets/words_es.txt"))),
+        "de" => DE.get_or_init(|| split_list(include_str!("../assets/words_de.txt"))),
+        _ => return (WORDS, ACCEPTABLE),
+    };
+    (words, words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_letter_has_a_key() {
+        for locale in Locale::value_variants() {
+            let keys: String = locale.layout().concat();
+            let (secret, acceptable) = bundled_word_lists(locale.code());
+            for word in secret.iter().chain(acceptable) {
+                assert!(
+                    word.chars().all(|c| keys.contains(c)),
+                    "{word} can't be typed with the {locale} layout"
+                );
+            }
+        }
+    }
+}
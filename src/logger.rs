@@ -0,0 +1,120 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::mpsc::{self, SyncSender},
+    thread::{self, JoinHandle},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crossterm::event::KeyEvent;
+
+use crate::game::Match;
+
+/// Lines that can wait to be written before new events start being dropped.
+const QUEUE_SIZE: usize = 256;
+
+/// Something that happened during a game, worth logging for debugging.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameEvent {
+    KeyPressed(KeyEvent),
+    GuessSubmitted { word: String, matches: Vec<Match> },
+    WordRevealed(String),
+}
+
+impl GameEvent {
+    /// The event as a JSON object, without the surrounding braces.
+    fn json_fields(&self) -> String {
+        match self {
+            GameEvent::KeyPressed(key) => format!(
+                r#""event":"key","key":{},"modifiers":{}"#,
+                json_string(&format!("{:?}", key.code)),
+                json_string(&format!("{:?}", key.modifiers))
+            ),
+            GameEvent::GuessSubmitted { word, matches } => {
+                let matches: String = matches.iter().map(|m| format!("{m:#}")).collect();
+                format!(
+                    r#""event":"guess","word":{},"matches":"{matches}""#,
+                    json_string(word)
+                )
+            }
+            GameEvent::WordRevealed(word) => {
+                format!(r#""event":"reveal","word":{}"#, json_string(word))
+            }
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Appends [`GameEvent`]s to a file as newline-delimited JSON. Writing
+/// happens on a background thread so that logging never holds up the game;
+/// if it falls too far behind, events are dropped.
+pub struct GameLogger {
+    sender: Option<SyncSender<String>>,
+    writer: Option<JoinHandle<io::Result<()>>>,
+    start_time: Instant,
+}
+
+impl GameLogger {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn new(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let (sender, receiver) = mpsc::sync_channel::<String>(QUEUE_SIZE);
+        let writer = thread::spawn(move || {
+            let mut file: BufWriter<File> = BufWriter::new(file);
+            // flush whenever the queue runs dry, so that the log is useful
+            // even if the game doesn't exit cleanly
+            while let Ok(line) = receiver.recv() {
+                writeln!(file, "{line}")?;
+                while let Ok(line) = receiver.try_recv() {
+                    writeln!(file, "{line}")?;
+                }
+                file.flush()?;
+            }
+            Ok(())
+        });
+        Ok(Self {
+            sender: Some(sender),
+            writer: Some(writer),
+            start_time: Instant::now(),
+        })
+    }
+
+    pub fn log_event(&mut self, event: &GameEvent) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis());
+        let line = format!(
+            r#"{{"timestamp":{timestamp},"elapsed_ms":{},{}}}"#,
+            self.start_time.elapsed().as_millis(),
+            event.json_fields()
+        );
+        if let Some(sender) = &self.sender {
+            sender.try_send(line).ok();
+        }
+    }
+}
+
+impl Drop for GameLogger {
+    fn drop(&mut self) {
+        // closing the channel lets the writer finish and flush the file
+        self.sender.take();
+        if let Some(writer) = self.writer.take() {
+            writer.join().ok();
+        }
+    }
+}
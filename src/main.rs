@@ -1,16 +1,253 @@
-use std::io;
+use std::{
+    io::{self, BufRead, Write},
+    process::ExitCode,
+    thread,
+};
 
-use yawc::game::Game;
+use clap::{parser::ValueSource, CommandFactory, FromArgMatches};
+use yawc::{
+    allocations::{self, CountingAllocator},
+    analysis,
+    capability::TerminalCapability,
+    config::{Command, Config},
+    crash,
+    game::{check_word, Game, GameRecord, Match},
+    generator, loader,
+    puzzle::PuzzleSet,
+    replay, solver, stats,
+    variant::{self, WordleVariant},
+};
 
-fn main() {
-    let run = || {
-        let game = Game::new()?;
-        game.main_loop()?;
-        io::Result::Ok(())
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() -> ExitCode {
+    let matches = Config::command().get_matches();
+    let mut config = Config::from_arg_matches(&matches)
+        .unwrap_or_else(|e| e.format(&mut Config::command()).exit());
+    // `--mode` and `--hard` pick the variant just like `--variant` does
+    let mode_given = matches.value_source("mode") == Some(ValueSource::CommandLine);
+    if config.variant.is_none() && (mode_given || config.hard) {
+        config.variant = Some(WordleVariant::from_config(&config));
+    }
+    if let Some(variant) = config.variant {
+        variant.apply(&mut config);
+    }
+    if let Err(errors) = Game::validate_config(&config) {
+        for error in errors {
+            eprintln!("error: {error}");
+        }
+        return ExitCode::from(2);
+    }
+    let batch = config.batch;
+    if config.verbose {
+        eprintln!("{}", TerminalCapability::detect());
+    }
+    if config.precompute {
+        solver::precompute_ranking();
+    } else if uses_solver(&config) {
+        thread::spawn(solver::word_entropy_ranking);
+    }
+    if let Some(path) = &config.puzzle_set {
+        match PuzzleSet::load(path, &config) {
+            Ok(set) => config.puzzles = Some(set),
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+    #[cfg(feature = "download")]
+    if let Some(url) = &config.wordlist_url {
+        config.custom_words = yawc::download::fetch_word_list(url, config.mode.word_len());
+    }
+    if let Some(path) = &config.word_list {
+        match loader::load_word_file(path, config.mode.word_len(), !batch) {
+            Ok(words) => config.custom_words = Some(words),
+            Err(e) => {
+                eprintln!("error: {}: {e}", path.display());
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    match run(config) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            if batch {
+                ExitCode::from(2)
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
+
+/// Whether `config` runs something that ranks guesses, so the ranking is
+/// worth starting in the background right away.
+fn uses_solver(config: &Config) -> bool {
+    match config.command {
+        Some(Command::Analysis { match_distribution }) => !match_distribution,
+        Some(Command::Themes | Command::Scores { .. }) => false,
+        Some(Command::Resume) => true,
+        None => {
+            !config.json_schema
+                && !config.batch
+                && config.benchmark_fps.is_none()
+                && config.replay.is_none()
+        }
+    }
+}
+
+/// Whether to ask which variant to play before the game, which is when
+/// nothing on the command line settles it already: not with `--variant` or
+/// `--mode`, nor for tournaments or the words of `--seed` and `--number`.
+fn shows_variant_menu(config: &Config) -> bool {
+    config.variant.is_none()
+        && !config.tournament
+        && !config.tournament_4
+        && config.seed.is_none()
+        && config.number.is_none()
+}
+
+fn run(config: Config) -> io::Result<ExitCode> {
+    match config.command {
+        Some(Command::Analysis {
+            match_distribution: true,
+        }) => stats::print_match_distribution(&mut io::stdout().lock())?,
+        Some(Command::Analysis { .. }) => analysis::print_report(&mut io::stdout().lock())?,
+        Some(Command::Themes) => Game::new_with_config(config)?.theme_gallery()?,
+        Some(Command::Scores { output_format }) => {
+            stats::print_scores(&mut io::stdout().lock(), output_format)?;
+        }
+        Some(Command::Resume) => {
+            let game = Game::new_with_config(config)?;
+            let game = match stats::load_snapshot()? {
+                Some(snapshot) => game.resume(&snapshot),
+                None => game,
+            };
+            game.main_loop()?;
+        }
+        None if config.json_schema => {
+            println!("{:#}", GameRecord::json_schema());
+        }
+        None if config.batch => return batch_mode(&config),
+        None if config.profile_solver => solver::print_profile(&mut io::stdout().lock())?,
+        None if config.benchmark_fps.is_some() => benchmark_fps(config)?,
+        None if config.replay.is_some() => return replay(config),
+        None => {
+            let mut config = config;
+            if shows_variant_menu(&config) {
+                match variant::select_variant(WordleVariant::from_config(&config))? {
+                    Some(variant) => variant.apply(&mut config),
+                    None => return Ok(ExitCode::SUCCESS),
+                }
+                // the variant picked may not go with the other flags
+                if let Err(errors) = Game::validate_config(&config) {
+                    for error in errors {
+                        eprintln!("error: {error}");
+                    }
+                    return Ok(ExitCode::from(2));
+                }
+            }
+            let mut game = Game::new_with_config(config)?;
+            if let Some(snapshot) = crash::take_crash_snapshot() {
+                if game.confirm("Yawc crashed last time. Resume? y/n")? {
+                    game = game.resume(&snapshot);
+                }
+            }
+            game.main_loop()?;
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Plays back the log given to `--replay`. Exits with `2` if it isn't one.
+fn replay(mut config: Config) -> io::Result<ExitCode> {
+    let Some(path) = config.replay.take() else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    let games = match replay::load_replay(&path, stats::MAX_GUESSES) {
+        Ok(games) => games,
+        Err(e) => {
+            eprintln!("error: {}: {e}", path.display());
+            return Ok(ExitCode::from(2));
+        }
+    };
+    Game::new_with_config(config)?.replay(&games)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Times redrawing the screen with `--benchmark-fps` and prints the results.
+#[allow(clippy::cast_precision_loss)]
+fn benchmark_fps(config: Config) -> io::Result<()> {
+    let frames = config.benchmark_fps.unwrap_or(0).max(1);
+    let game = Game::new_with_config(config)?;
+    let before = allocations::allocations().unwrap_or(0);
+    let total = game.benchmark_redraws(frames)?;
+    let allocations = allocations::allocations().unwrap_or(0) - before;
+
+    let per_frame = total / frames;
+    println!("frames:      {frames}");
+    println!("total:       {total:.2?}");
+    println!("per frame:   {per_frame:.2?}");
+    println!(
+        "fps:         {:.0}",
+        f64::from(frames) / total.as_secs_f64()
+    );
+    println!(
+        "allocations: {allocations} ({:.1} per frame)",
+        allocations as f64 / f64::from(frames)
+    );
+    Ok(())
+}
+
+/// Plays a single game reading guesses from stdin, one per line, and printing
+/// each guess along with its matches. Exits with `0` on win, `1` on loss and
+/// `2` on invalid input. With the `progress` feature, the guesses made so far
+/// are also counted on stderr.
+fn batch_mode(config: &Config) -> io::Result<ExitCode> {
+    let secret_word = match &config.word {
+        Some(word) => word.as_str(),
+        None => generator::for_config(config).next_word(),
     };
 
-    if let Err(e) = run() {
-        eprintln!("Error: {e}");
-        std::process::exit(1);
+    #[cfg(feature = "progress")]
+    let progress = indicatif::ProgressBar::new_spinner().with_style(
+        indicatif::ProgressStyle::with_template("{spinner} {prefix} ({pos} guesses)")
+            .expect("the template is valid"),
+    );
+
+    let mut stdout = io::stdout().lock();
+    let mut guesses = 0;
+    for line in io::stdin().lock().lines() {
+        let guess = line?.trim().to_lowercase();
+        if guess.is_empty() {
+            continue;
+        }
+        if !config.is_valid_word(&guess) {
+            eprintln!("Error: {guess} is not in the word list");
+            return Ok(ExitCode::from(2));
+        }
+
+        let matches_ = check_word(secret_word, &guess);
+        let result: String = matches_.iter().map(|m| format!("{m:#}")).collect();
+        writeln!(stdout, "{guess} {result}")?;
+        #[cfg(feature = "progress")]
+        {
+            progress.set_prefix(format!("{guess} {result}"));
+            progress.inc(1);
+        }
+
+        guesses += 1;
+        if matches_ == [Match::Correct; 5] {
+            return Ok(ExitCode::SUCCESS);
+        }
+        if guesses >= stats::MAX_GUESSES {
+            break;
+        }
     }
+
+    Ok(ExitCode::FAILURE)
 }
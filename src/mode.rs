@@ -0,0 +1,53 @@
+use clap::ValueEnum;
+
+use crate::{
+    game::is_valid_word,
+    words::{ACCEPTABLE, WORDS, WORDS_PLUS},
+};
+
+/// Variant of the game, deciding how long the words are.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// 5-letter words, like the original Wordle
+    #[default]
+    Classic,
+    /// 7-letter words from a smaller list, still in 6 guesses
+    Plus,
+}
+
+impl Mode {
+    #[must_use]
+    pub fn word_len(self) -> usize {
+        match self {
+            Mode::Classic => 5,
+            Mode::Plus => 7,
+        }
+    }
+
+    /// Words the secret word is picked from.
+    #[must_use]
+    pub fn secret_words(self) -> &'static [&'static str] {
+        match self {
+            Mode::Classic => WORDS,
+            Mode::Plus => WORDS_PLUS,
+        }
+    }
+
+    /// All the words that can be guessed in this mode.
+    pub fn guessable_words(self) -> impl Iterator<Item = &'static str> {
+        let acceptable: &[&str] = match self {
+            Mode::Classic => ACCEPTABLE,
+            Mode::Plus => &[],
+        };
+        self.secret_words().iter().chain(acceptable).copied()
+    }
+
+    /// Whether `word` can be guessed in this mode.
+    #[must_use]
+    pub fn is_valid_word(self, word: &str) -> bool {
+        match self {
+            Mode::Classic => is_valid_word(word),
+            Mode::Plus => WORDS_PLUS.contains(&word),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fs, io, path::Path};
+
+use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, SeedableRng};
+use serde::Deserialize;
+
+use crate::{config::Config, generator::SecretWordGenerator};
+
+/// A secret word picked by hand, with a message to go along with it.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Puzzle {
+    pub word: String,
+    /// Shown in the title bar while the word is being guessed.
+    #[serde(default)]
+    pub intro_message: String,
+    /// How hard the word is meant to be, up to whoever made the set.
+    #[serde(default)]
+    pub difficulty: u8,
+}
+
+/// Puzzles loaded with `--puzzle-set`, from a JSON array like
+/// `[{ "word": "crane", "intro_message": "A bird-related word!", "difficulty": 2 }]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct PuzzleSet {
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl PuzzleSet {
+    /// Reads the set at `path`, checking that every word is one `config`
+    /// can have as the secret word, so that it's among the words left to
+    /// guess.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path, config: &Config) -> io::Result<Self> {
+        let invalid = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {message}", path.display()),
+            )
+        };
+        let contents = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        let mut set: Self = serde_json::from_str(&contents).map_err(|e| invalid(e.to_string()))?;
+        if set.puzzles.is_empty() {
+            return Err(invalid("there are no puzzles".to_owned()));
+        }
+        for puzzle in &mut set.puzzles {
+            puzzle.word = puzzle.word.trim().to_lowercase();
+            if !config.secret_words().contains(&puzzle.word.as_str()) {
+                return Err(invalid(format!(
+                    "{:?} is not in the list of secret words",
+                    puzzle.word
+                )));
+            }
+        }
+        Ok(set)
+    }
+}
+
+/// Goes through a [`PuzzleSet`] in random order, the same one for a given
+/// seed, starting over once every puzzle was played.
+pub struct PuzzleGenerator {
+    puzzles: Vec<(&'static str, Puzzle)>,
+    /// Index of the puzzle that will be played next.
+    next: usize,
+}
+
+impl PuzzleGenerator {
+    /// `None` if `set` has no puzzles to go through.
+    #[must_use]
+    pub fn new(set: PuzzleSet, seed: Option<u64>) -> Option<Self> {
+        if set.puzzles.is_empty() {
+            return None;
+        }
+        let mut puzzles: Vec<_> = set
+            .puzzles
+            .into_iter()
+            // the words are used for the rest of the program, like the
+            // built-in ones
+            .map(|p| (&*Box::leak(p.word.clone().into_boxed_str()), p))
+            .collect();
+        match seed {
+            Some(seed) => puzzles.shuffle(&mut StdRng::seed_from_u64(seed)),
+            None => puzzles.shuffle(&mut thread_rng()),
+        }
+        Some(Self { puzzles, next: 0 })
+    }
+}
+
+impl SecretWordGenerator for PuzzleGenerator {
+    fn next_word(&mut self) -> &'static str {
+        let (word, _) = self.puzzles[self.next % self.puzzles.len()];
+        self.next += 1;
+        word
+    }
+
+    fn intro_message(&self) -> Option<String> {
+        let last = self.next.checked_sub(1)?;
+        let (_, puzzle) = &self.puzzles[last % self.puzzles.len()];
+        let message = match (puzzle.intro_message.as_str(), puzzle.difficulty) {
+            ("", 0) => return None,
+            (message, 0) => message.to_owned(),
+            ("", difficulty) => format!("Difficulty {difficulty}"),
+            (message, difficulty) => format!("{message} (difficulty {difficulty})"),
+        };
+        Some(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::words::{ACCEPTABLE, WORDS};
+
+    fn puzzle(word: &str, intro_message: &str) -> Puzzle {
+        Puzzle {
+            word: word.to_owned(),
+            intro_message: intro_message.to_owned(),
+            difficulty: 0,
+        }
+    }
+
+    #[test]
+    fn empty_set_has_no_generator() {
+        assert!(PuzzleGenerator::new(PuzzleSet::default(), Some(1)).is_none());
+    }
+
+    #[test]
+    fn every_puzzle_comes_up_before_any_repeats() {
+        let set = PuzzleSet {
+            puzzles: vec![
+                puzzle("crane", ""),
+                puzzle("slate", ""),
+                puzzle("pious", ""),
+            ],
+        };
+        let mut generator = PuzzleGenerator::new(set, Some(1)).unwrap();
+        let mut words: Vec<_> = (0..3).map(|_| generator.next_word()).collect();
+        let again: Vec<_> = (0..3).map(|_| generator.next_word()).collect();
+        assert_eq!(words, again);
+        words.sort_unstable();
+        assert_eq!(words, ["crane", "pious", "slate"]);
+    }
+
+    #[test]
+    fn puzzle_without_a_message_has_none() {
+        let set = PuzzleSet {
+            puzzles: vec![puzzle("crane", "A bird!"), puzzle("slate", "")],
+        };
+        let mut generator = PuzzleGenerator::new(set, Some(1)).unwrap();
+        assert_eq!(generator.intro_message(), None);
+        for _ in 0..4 {
+            let expected = (generator.next_word() == "crane").then(|| "A bird!".to_owned());
+            assert_eq!(generator.intro_message(), expected);
+        }
+    }
+
+    #[test]
+    fn words_that_cant_be_secret_are_rejected() {
+        let path = env::temp_dir().join(format!("yawc-test-puzzles-{}", std::process::id()));
+        let config = Config::default();
+        fs::write(
+            &path,
+            r#"[{ "word": "Crane", "intro_message": "A bird!" }]"#,
+        )
+        .unwrap();
+        let set = PuzzleSet::load(&path, &config).unwrap();
+        assert_eq!(set.puzzles, [puzzle("crane", "A bird!")]);
+
+        let guessable_only = ACCEPTABLE.iter().find(|w| !WORDS.contains(w)).unwrap();
+        fs::write(&path, format!(r#"[{{ "word": "{guessable_only}" }}]"#)).unwrap();
+        assert!(PuzzleSet::load(&path, &config).is_err());
+        fs::write(&path, "[]").unwrap();
+        assert!(PuzzleSet::load(&path, &config).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,96 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor, queue,
+    style::{self, Color},
+};
+use qrcode::{EcLevel, QrCode};
+
+use crate::raw::Terminal;
+
+/// Light modules drawn around the code, so that it can be told apart from
+/// whatever is next to it.
+const QUIET_ZONE: usize = 2;
+
+/// A QR code of `data` with the lowest level of error correction, which
+/// keeps it small enough for a terminal. [`None`] if `data` is too long for
+/// any.
+fn encode(data: &str) -> Option<QrCode> {
+    QrCode::with_error_correction_level(data, EcLevel::L).ok()
+}
+
+/// Columns and rows [`render_qr`] takes to draw `data`, two modules to a
+/// row, or [`None`] if `data` is too long for a QR code.
+///
+/// ```
+/// use yawc::qr::drawn_size;
+///
+/// assert_eq!(drawn_size("yawc"), Some((25, 13)));
+/// assert_eq!(drawn_size(&"a".repeat(3000)), None);
+/// ```
+#[must_use]
+pub fn drawn_size(data: &str) -> Option<(u16, u16)> {
+    let side = encode(data)?.width() + QUIET_ZONE * 2;
+    Some((
+        u16::try_from(side).unwrap_or(u16::MAX),
+        u16::try_from(side.div_ceil(2)).unwrap_or(u16::MAX),
+    ))
+}
+
+/// Draws a QR code of `data` with its top left corner at `origin`, two
+/// modules to a character. The light modules are the ones drawn, in white on
+/// black, so that it reads the same on light and dark terminals.
+///
+/// # Errors
+///
+/// If `data` is too long for a QR code, or the terminal can't be written to.
+pub fn render_qr(data: &str, term: &mut Terminal, origin: (u16, u16)) -> io::Result<()> {
+    let code = encode(data).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "too much data for a QR code")
+    })?;
+    let width = code.width();
+    let side = width + QUIET_ZONE * 2;
+    let colors = code.to_colors();
+    // counting the quiet zone in
+    let light = |x: usize, y: usize| {
+        let (x, y) = (x.wrapping_sub(QUIET_ZONE), y.wrapping_sub(QUIET_ZONE));
+        x >= width || y >= width || colors[y * width + x] == qrcode::Color::Light
+    };
+    queue!(
+        term,
+        style::SetForegroundColor(Color::White),
+        style::SetBackgroundColor(Color::Black)
+    )?;
+    for (row, y) in (origin.1..).zip((0..side).step_by(2)) {
+        let line: String = (0..side)
+            .map(|x| match (light(x, y), y + 1 < side && light(x, y + 1)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            })
+            .collect();
+        queue!(term, cursor::MoveTo(origin.0, row), style::Print(line))?;
+    }
+    queue!(term, style::ResetColor)?;
+    term.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::SHARE_VIEWER_URL;
+
+    #[test]
+    fn encode_picks_the_smallest_version() {
+        assert_eq!(encode("yawc").unwrap().width(), 21);
+        assert!(encode(&"a".repeat(3000)).is_none());
+    }
+
+    #[test]
+    fn share_link_fits_a_small_terminal() {
+        let link = format!("{SHARE_VIEWER_URL}?h={}", "Y3JhbmXy".repeat(6));
+        let (width, height) = drawn_size(&link).unwrap();
+        assert!(width <= 80 && height < 40, "{width}x{height}");
+    }
+}
@@ -1,50 +1,236 @@
 use crossterm::{
-    cursor, execute,
+    cursor,
+    event::{self, Event},
+    execute,
     style::{self, Color},
     terminal,
 };
 
-use std::io::{self, stdout, Stdout};
+use crate::{
+    capability::{ascii_fallback, TerminalCapability},
+    theme::detect_color_support,
+};
+
+use std::{
+    collections::VecDeque,
+    io::{self, stdout, Stdout, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+pub struct Terminal {
+    out: Output,
+    capability: TerminalCapability,
+    /// Set by the `SIGWINCH` handler, as some terminal multiplexers resize
+    /// the terminal without crossterm reporting it.
+    resized: Arc<AtomicBool>,
+    /// Events handed out instead of the terminal's by one that isn't the
+    /// real terminal, see [`Terminal::with_events`].
+    events: VecDeque<Event>,
+    #[cfg(unix)]
+    sigwinch: Option<signal_hook::SigId>,
+}
 
-pub struct Terminal(Stdout);
+enum Output {
+    Stdout(Stdout),
+    /// Writes somewhere else, pretending to be a terminal of this size.
+    Fixed(Box<dyn Write>, TerminalSize),
+}
 
-impl std::ops::Deref for Terminal {
-    type Target = Stdout;
+/// Where the size of the screen comes from, so that it can be faked.
+pub trait TerminalInfo {
+    /// Current size of the terminal as `(width, height)`.
+    #[allow(clippy::missing_errors_doc)]
+    fn size(&self) -> io::Result<(u16, u16)>;
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TerminalSize {
+    pub width: u16,
+    pub height: u16,
+}
+
+impl TerminalSize {
+    /// Whether `self` is at least as big as `other` in both dimensions.
+    #[must_use]
+    pub fn fits(&self, other: TerminalSize) -> bool {
+        self.width >= other.width && self.height >= other.height
     }
 }
 
-impl std::ops::DerefMut for Terminal {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl Write for Terminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.out {
+            Output::Stdout(out) if !self.capability.unicode => {
+                // a character split between two writes goes out as it is
+                match std::str::from_utf8(buf) {
+                    Ok(text) => {
+                        out.write_all(ascii_fallback(text).as_bytes())?;
+                        Ok(buf.len())
+                    }
+                    Err(_) => out.write(buf),
+                }
+            }
+            Output::Stdout(out) => out.write(buf),
+            Output::Fixed(out, _) => out.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.out {
+            Output::Stdout(out) => out.flush(),
+            Output::Fixed(out, _) => out.flush(),
+        }
     }
 }
 
 impl Terminal {
     pub(crate) fn new() -> io::Result<Self> {
+        let capability = TerminalCapability::detect();
         let mut stdout = stdout();
         terminal::enable_raw_mode()?;
-        execute!(
-            stdout,
-            terminal::EnterAlternateScreen,
-            cursor::Hide,
-            style::SetBackgroundColor(Color::Black)
-        )?;
+        if capability.alternate_screen {
+            execute!(stdout, terminal::EnterAlternateScreen)?;
+        } else {
+            execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+        }
+        if capability.cursor_visibility {
+            execute!(stdout, cursor::Hide)?;
+        }
+        execute!(stdout, style::SetBackgroundColor(Color::Black))?;
+
+        let resized = Arc::new(AtomicBool::new(false));
+        #[cfg(unix)]
+        let sigwinch =
+            signal_hook::flag::register(signal_hook::consts::SIGWINCH, Arc::clone(&resized))?;
+
+        Ok(Self {
+            out: Output::Stdout(stdout),
+            capability,
+            resized,
+            events: VecDeque::new(),
+            #[cfg(unix)]
+            sigwinch: Some(sigwinch),
+        })
+    }
+
+    /// A terminal that draws nothing and never changes size, for measuring
+    /// how long drawing takes without the cost of actually showing it.
+    #[must_use]
+    pub fn headless(size: TerminalSize) -> Self {
+        Self::with_fixed_size(size.width, size.height, Box::new(io::sink()))
+    }
+
+    /// A terminal that never changes size, writing everything to `output`
+    /// instead of the screen and leaving the real terminal alone, like for
+    /// checking what gets drawn on a small screen.
+    #[must_use]
+    pub fn with_fixed_size(width: u16, height: u16, output: Box<dyn Write>) -> Self {
+        Self {
+            out: Output::Fixed(output, TerminalSize { width, height }),
+            capability: TerminalCapability::from_env(
+                &std::env::var("TERM").unwrap_or_default(),
+                "UTF-8",
+                detect_color_support(),
+            ),
+            resized: Arc::new(AtomicBool::new(false)),
+            events: VecDeque::new(),
+            #[cfg(unix)]
+            sigwinch: None,
+        }
+    }
+
+    /// Makes a terminal that isn't the real one hand out `events` as if they
+    /// were typed in, like for playing out a game in a test. A resize among
+    /// them resizes the terminal when it's read.
+    #[must_use]
+    pub fn with_events(mut self, events: impl IntoIterator<Item = Event>) -> Self {
+        self.events.extend(events);
+        self
+    }
+
+    /// Whether an event can be read right away, waiting up to `timeout` for
+    /// one. A terminal that isn't the real one waits for nothing once it
+    /// runs out of the events it was given, as no others come.
+    pub(crate) fn poll_event(&mut self, timeout: Duration) -> io::Result<bool> {
+        match self.out {
+            Output::Stdout(_) => event::poll(timeout),
+            Output::Fixed(..) if self.events.is_empty() => {
+                thread::sleep(timeout);
+                Ok(false)
+            }
+            Output::Fixed(..) => Ok(true),
+        }
+    }
 
-        Ok(Self(stdout))
+    /// The next event, waiting for it. Fails for a terminal that isn't the
+    /// real one once it runs out of events, rather than waiting forever.
+    pub(crate) fn read_event(&mut self) -> io::Result<Event> {
+        match &mut self.out {
+            Output::Stdout(_) => event::read(),
+            Output::Fixed(_, size) => {
+                let event = self
+                    .events
+                    .pop_front()
+                    .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+                if let Event::Resize(width, height) = event {
+                    *size = TerminalSize { width, height };
+                }
+                Ok(event)
+            }
+        }
+    }
+
+    /// What the terminal can do. One that isn't the real terminal is taken
+    /// to speak UTF-8, as nothing shows what it draws anyway.
+    #[must_use]
+    pub fn capability(&self) -> TerminalCapability {
+        self.capability
+    }
+
+    /// Whether the terminal was resized since the last call. Only ever true
+    /// on Unix, elsewhere crossterm's resize events are all there is.
+    pub(crate) fn take_resize(&self) -> bool {
+        self.resized.swap(false, Ordering::Relaxed)
     }
 }
+
+impl TerminalInfo for Terminal {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        match &self.out {
+            Output::Stdout(_) => terminal::size(),
+            Output::Fixed(_, size) => Ok((size.width, size.height)),
+        }
+    }
+}
+
 impl Drop for Terminal {
     fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(sigwinch) = self.sigwinch {
+            signal_hook::low_level::unregister(sigwinch);
+        }
+        let Output::Stdout(out) = &mut self.out else {
+            return;
+        };
         terminal::disable_raw_mode().ok();
-        execute!(
-            self.0,
-            style::ResetColor,
-            cursor::Show,
-            terminal::LeaveAlternateScreen
-        )
-        .ok();
+        execute!(out, style::ResetColor).ok();
+        if self.capability.cursor_visibility {
+            execute!(out, cursor::Show).ok();
+        }
+        if self.capability.alternate_screen {
+            execute!(out, terminal::LeaveAlternateScreen).ok();
+        } else {
+            execute!(
+                out,
+                terminal::Clear(terminal::ClearType::All),
+                cursor::MoveTo(0, 0)
+            )
+            .ok();
+        }
     }
 }
@@ -0,0 +1,203 @@
+use std::{
+    fs, io,
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::KeyCode;
+
+use crate::game::{GuessEntry, GuessHistory, Match};
+
+/// Time between two guesses at 1× speed.
+pub const GUESS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long `F` or `S` keep counting as held after being pressed. Terminals
+/// don't tell when a key is let go, only repeat it while it's held, and the
+/// first repeat comes about half a second after the press.
+const HOLD: Duration = Duration::from_millis(700);
+
+/// Speed of `--replay`, changed by the keys pressed while it plays: `F` held
+/// down fast-forwards at 10×, `S` held down slows down to 0.25×, and `Space`
+/// pauses.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+///
+/// use crossterm::event::KeyCode;
+/// use yawc::replay::ReplayController;
+///
+/// let start = Instant::now();
+/// let mut replay = ReplayController::default();
+/// assert_eq!(replay.label(), "[1.0×]");
+/// assert!(!replay.advance(Duration::from_millis(500), start));
+/// assert!(replay.advance(Duration::from_millis(500), start));
+///
+/// assert!(replay.handle_key(KeyCode::Char('f'), start));
+/// assert_eq!(replay.label(), "[10.0×]");
+/// assert!(replay.advance(Duration::from_millis(100), start));
+/// // let go of `F`
+/// assert!(!replay.advance(Duration::ZERO, start + Duration::from_secs(1)));
+/// assert_eq!(replay.label(), "[1.0×]");
+///
+/// assert!(replay.handle_key(KeyCode::Char(' '), start));
+/// assert_eq!(replay.label(), "[paused]");
+/// assert!(!replay.advance(Duration::from_secs(5), start));
+/// assert!(!replay.handle_key(KeyCode::Esc, start));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReplayController {
+    pub speed_multiplier: f32,
+    pub paused: bool,
+    /// When the `F` or `S` being held counts as let go, unless it repeats.
+    held_until: Option<Instant>,
+    /// Time spent on the current guess, at 1× speed.
+    progress: Duration,
+}
+
+impl Default for ReplayController {
+    fn default() -> Self {
+        Self {
+            speed_multiplier: 1.0,
+            paused: false,
+            held_until: None,
+            progress: Duration::ZERO,
+        }
+    }
+}
+
+impl ReplayController {
+    /// Changes the speed for `key`, pressed at `now`. Returns `false` if it
+    /// stops the replay.
+    pub fn handle_key(&mut self, key: KeyCode, now: Instant) -> bool {
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => return false,
+            KeyCode::Char('f' | 'F') => self.hold(10.0, now),
+            KeyCode::Char('s' | 'S') => self.hold(0.25, now),
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            _ => {}
+        }
+        true
+    }
+
+    fn hold(&mut self, speed_multiplier: f32, now: Instant) {
+        self.speed_multiplier = speed_multiplier;
+        self.held_until = Some(now + HOLD);
+    }
+
+    /// Moves the replay on by `elapsed` of real time, up to `now`. Returns
+    /// whether the next guess is due.
+    pub fn advance(&mut self, elapsed: Duration, now: Instant) -> bool {
+        if self.held_until.is_some_and(|until| now >= until) {
+            self.held_until = None;
+            self.speed_multiplier = 1.0;
+        }
+        if self.paused {
+            return false;
+        }
+        self.progress += elapsed.mul_f32(self.speed_multiplier);
+        if self.progress >= GUESS_INTERVAL {
+            self.progress = Duration::ZERO;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The speed as shown in the corner of the screen, like `[1.0×]`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        if self.paused {
+            "[paused]".to_owned()
+        } else {
+            format!("[{:?}×]", self.speed_multiplier)
+        }
+    }
+}
+
+/// Reads the games of a `--log-file` log for `--replay`. A game ends with a
+/// guess that's all correct, with the word being revealed, or after
+/// `max_guesses` guesses; key presses are skipped.
+///
+/// ```
+/// use yawc::{game::Match::*, replay::parse_replay};
+///
+/// let log = r#"
+/// {"timestamp":0,"elapsed_ms":0,"event":"key","key":"Enter","modifiers":"NONE"}
+/// {"timestamp":0,"elapsed_ms":0,"event":"guess","word":"slate","matches":"IIMIC"}
+/// {"timestamp":0,"elapsed_ms":0,"event":"guess","word":"crane","matches":"CCCCC"}
+/// {"timestamp":0,"elapsed_ms":0,"event":"guess","word":"pious","matches":"IIIII"}
+/// {"timestamp":0,"elapsed_ms":0,"event":"reveal","word":"crane"}
+/// "#;
+/// let games = parse_replay(log, 6).unwrap();
+/// assert_eq!(games.len(), 2);
+/// assert_eq!(games[0][0].word, "slate");
+/// assert_eq!(games[0][0].matches, [Incorrect, Incorrect, Misplaced, Incorrect, Correct]);
+/// assert_eq!(games[1][0].word, "pious");
+///
+/// assert!(parse_replay("not json", 6).is_err());
+/// assert!(parse_replay("", 6).is_err());
+/// ```
+///
+/// # Errors
+///
+/// If a line isn't a logged event, or there are no guesses at all.
+pub fn parse_replay(log: &str, max_guesses: usize) -> io::Result<Vec<GuessHistory>> {
+    let invalid = |n: usize, message: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("line {}: {message}", n + 1),
+        )
+    };
+    let mut games = Vec::new();
+    let mut game = GuessHistory::new();
+    for (n, line) in log.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| invalid(n, &e.to_string()))?;
+        match event["event"].as_str() {
+            Some("guess") => {
+                let (Some(word), Some(matches)) =
+                    (event["word"].as_str(), event["matches"].as_str())
+                else {
+                    return Err(invalid(n, "a guess needs a word and matches"));
+                };
+                let matches = matches
+                    .chars()
+                    .map(|c| Match::from_str(&c.to_string()))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| invalid(n, &e.to_string()))?;
+                let won = matches.iter().all(|&m| m == Match::Correct);
+                game.push(GuessEntry::new(word.to_owned(), matches));
+                if won || game.len() >= max_guesses {
+                    games.push(std::mem::take(&mut game));
+                }
+            }
+            Some("reveal") if !game.is_empty() => games.push(std::mem::take(&mut game)),
+            Some(_) => {}
+            None => return Err(invalid(n, "not a logged event")),
+        }
+    }
+    if !game.is_empty() {
+        games.push(game);
+    }
+    if games.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "there are no guesses to replay",
+        ));
+    }
+    Ok(games)
+}
+
+/// [`parse_replay`] of the log at `path`.
+///
+/// # Errors
+///
+/// If the file can't be read, or [`parse_replay`] fails.
+pub fn load_replay(path: &Path, max_guesses: usize) -> io::Result<Vec<GuessHistory>> {
+    let contents = fs::read_to_string(path)?;
+    parse_replay(&contents, max_guesses)
+}
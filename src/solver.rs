@@ -0,0 +1,435 @@
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        OnceLock,
+    },
+};
+
+use crate::{
+    game::{check_word, GameRecord, Match},
+    stats::MAX_GUESSES,
+    words::WORDS,
+};
+
+/// See [`word_entropy_ranking`].
+static RANKING: OnceLock<Vec<(&'static str, f64)>> = OnceLock::new();
+
+/// Every word of [`WORDS`] with its entropy as a first guess, i.e. against
+/// the whole list, best first. Worked out once, in about a second, which
+/// `main` starts in the background as soon as yawc does; until then this
+/// waits for it.
+#[must_use]
+pub fn word_entropy_ranking() -> &'static [(&'static str, f64)] {
+    RANKING.get_or_init(|| rank_words(false))
+}
+
+/// Works out [`word_entropy_ranking`] now, counting the words done on
+/// stderr, unless it already is.
+pub fn precompute_ranking() {
+    RANKING.get_or_init(|| rank_words(true));
+}
+
+fn rank_words(progress: bool) -> Vec<(&'static str, f64)> {
+    let done = AtomicUsize::new(0);
+    let rank = |word: &&'static str| {
+        let bits = compute_entropy(word, WORDS);
+        let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+        if progress && (done.is_multiple_of(100) || done == WORDS.len()) {
+            eprint!("\rRanked {done}/{}", WORDS.len());
+        }
+        (*word, bits)
+    };
+
+    #[cfg(feature = "rayon")]
+    let mut ranking: Vec<_> = {
+        use rayon::prelude::*;
+        WORDS.par_iter().map(rank).collect()
+    };
+    #[cfg(not(feature = "rayon"))]
+    let mut ranking: Vec<_> = WORDS.iter().map(rank).collect();
+    if progress {
+        eprintln!();
+    }
+    // stable, so that ties stay in `WORDS` order
+    ranking.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranking
+}
+
+/// Best first guess, the top of [`word_entropy_ranking`].
+///
+/// ```
+/// use yawc::solver::{best_guess, word_entropy_ranking};
+///
+/// assert_eq!(best_guess(), "raise");
+/// let ranking = word_entropy_ranking();
+/// assert!(ranking.windows(2).all(|w| w[0].1 >= w[1].1));
+/// ```
+#[must_use]
+pub fn best_guess() -> &'static str {
+    word_entropy_ranking()[0].0
+}
+
+/// The candidate telling the most about the others, with its entropy in
+/// bits. Taken from [`word_entropy_ranking`] while nothing has narrowed down
+/// [`WORDS`] yet, worked out against each other otherwise.
+///
+/// ```
+/// use yawc::{solver::best_candidate, words::WORDS};
+///
+/// assert_eq!(best_candidate(WORDS).map(|(w, _)| w), Some("raise"));
+/// assert_eq!(best_candidate(&["crane", "crate"]).map(|(_, bits)| bits), Some(1.0));
+/// assert_eq!(best_candidate(&[]), None);
+/// ```
+#[must_use]
+pub fn best_candidate(candidates: &[&'static str]) -> Option<(&'static str, f64)> {
+    if candidates == WORDS {
+        return word_entropy_ranking().first().copied();
+    }
+    best_of(
+        candidates.iter().copied(),
+        candidates,
+        &AtomicBool::new(false),
+    )
+}
+
+/// Most candidates [`suggest_candidate`] scores, as each is scored against
+/// all the others.
+pub const MAX_SCORED: usize = 500;
+
+/// [`best_candidate`] for a suggestion that may stop being wanted: gives up
+/// with `None` once `cancel` is set. With more than [`MAX_SCORED`]
+/// candidates, only an even sample of them is scored, still against all of
+/// them.
+///
+/// ```
+/// use std::sync::atomic::AtomicBool;
+///
+/// use yawc::solver::suggest_candidate;
+///
+/// let candidates = ["crane", "crate"];
+/// assert_eq!(
+///     suggest_candidate(&candidates, &AtomicBool::new(false)).map(|(_, bits)| bits),
+///     Some(1.0)
+/// );
+/// assert_eq!(suggest_candidate(&candidates, &AtomicBool::new(true)), None);
+/// ```
+#[must_use]
+pub fn suggest_candidate(
+    candidates: &[&'static str],
+    cancel: &AtomicBool,
+) -> Option<(&'static str, f64)> {
+    if candidates == WORDS {
+        return word_entropy_ranking().first().copied();
+    }
+    let step = candidates.len().div_ceil(MAX_SCORED).max(1);
+    best_of(candidates.iter().step_by(step).copied(), candidates, cancel)
+}
+
+/// The one of `guesses` with the most entropy against `candidates`, the last
+/// of them on a tie, unless `cancel` gets set first.
+fn best_of(
+    guesses: impl Iterator<Item = &'static str>,
+    candidates: &[&'static str],
+    cancel: &AtomicBool,
+) -> Option<(&'static str, f64)> {
+    let mut best: Option<(&'static str, f64)> = None;
+    for guess in guesses {
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+        let bits = compute_entropy(guess, candidates);
+        if best.is_none_or(|(_, b)| bits >= b) {
+            best = Some((guess, bits));
+        }
+    }
+    best
+}
+
+/// Greedy solver picking the candidate that maximizes the entropy of the
+/// response at each step.
+pub struct Solver {
+    candidates: Vec<&'static str>,
+}
+
+impl Solver {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            candidates: WORDS.to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn candidates(&self) -> &[&'static str] {
+        &self.candidates
+    }
+
+    #[must_use]
+    pub fn next_guess(&self) -> Option<&'static str> {
+        best_candidate(&self.candidates).map(|(c, _)| c)
+    }
+
+    pub fn update(&mut self, guess: &str, matches_: [Match; 5]) {
+        self.candidates.retain(|c| check_word(c, guess) == matches_);
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Number of different responses a guess can get, `3^5`.
+const PATTERNS: usize = 243;
+
+/// Base-3 encoding of `matches`, unique for each of the [`PATTERNS`].
+fn pattern_index(matches: [Match; 5]) -> usize {
+    matches.iter().fold(0, |index, m| {
+        index * 3
+            + match m {
+                Match::Incorrect => 0,
+                Match::Misplaced => 1,
+                Match::Correct => 2,
+            }
+    })
+}
+
+/// Number of `candidates` giving each response to `guess`.
+fn response_buckets(guess: &str, candidates: &[&str]) -> [usize; PATTERNS] {
+    let mut buckets = [0; PATTERNS];
+    for candidate in candidates {
+        buckets[pattern_index(check_word(candidate, guess))] += 1;
+    }
+    buckets
+}
+
+/// Shannon entropy, in bits, of the responses `word` gets against
+/// `candidates`, i.e. how much information guessing it is expected to give.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn compute_entropy(word: &str, candidates: &[&str]) -> f64 {
+    let total = candidates.len() as f64;
+    response_buckets(word, candidates)
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            let p = n as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Average number of candidates left after guessing `guess`, assuming every
+/// candidate is equally likely to be the secret word.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn expected_remaining(guess: &str, candidates: &[&str]) -> f64 {
+    response_buckets(guess, candidates)
+        .iter()
+        .map(|&n| (n * n) as f64)
+        .sum::<f64>()
+        / candidates.len() as f64
+}
+
+/// Candidate starting with `prefix` that leaves the fewest candidates on
+/// average once guessed. Responses are only scored for 5-letter words, for
+/// longer ones the first candidate is taken.
+#[must_use]
+pub fn best_completion(prefix: &str, candidates: &[&str]) -> Option<String> {
+    if candidates.first().is_some_and(|c| c.chars().count() != 5) {
+        return candidates
+            .iter()
+            .find(|c| c.starts_with(prefix))
+            .map(|c| (*c).to_owned());
+    }
+    candidates
+        .iter()
+        .filter(|c| c.starts_with(prefix))
+        .map(|c| (c, expected_remaining(c, candidates)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(c, _)| (*c).to_owned())
+}
+
+/// Number of guesses the solver needs to find `secret_word`, or [`None`] if
+/// it didn't manage to in [`MAX_GUESSES`].
+#[must_use]
+pub fn solve(secret_word: &str) -> Option<usize> {
+    let mut solver = Solver::new();
+    for guesses in 1..=MAX_GUESSES {
+        let guess = solver.next_guess()?;
+        let matches_ = check_word(secret_word, guess);
+        if matches_ == [Match::Correct; 5] {
+            return Some(guesses);
+        }
+        solver.update(guess, matches_);
+    }
+    None
+}
+
+/// What the solver would have played instead of one of the player's guesses.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BotMove {
+    pub player_guess: String,
+    pub bot_guess: &'static str,
+    /// Bits of information the player's guess actually gave, i.e. `log2` of
+    /// how many times fewer candidates were left after it.
+    pub player_info_bits: f64,
+    /// Bits of information the bot's guess would have given in its place.
+    pub bot_info_bits: f64,
+}
+
+impl BotMove {
+    #[must_use]
+    pub fn is_optimal(&self) -> bool {
+        self.player_guess == self.bot_guess
+    }
+}
+
+/// Bits of information `guess` gives about `secret_word` when `candidates`
+/// are still possible.
+#[allow(clippy::cast_precision_loss)]
+fn information_gained(guess: &str, secret_word: &str, candidates: &[&str]) -> f64 {
+    let matches_ = check_word(secret_word, guess);
+    let left = candidates
+        .iter()
+        .filter(|c| check_word(c, guess) == matches_)
+        .count();
+    (candidates.len() as f64 / left.max(1) as f64).log2()
+}
+
+/// Compares each guess of a finished game with what the solver would have
+/// played knowing the same, i.e. after the player's earlier guesses. Empty
+/// for games not played with 5-letter words.
+#[must_use]
+pub fn bot_analysis(record: &GameRecord) -> Vec<BotMove> {
+    let secret_word = record.secret_word.as_str();
+    let mut solver = Solver::new();
+    let mut moves = Vec::new();
+    for entry in &record.guesses {
+        let Ok(matches_) = <[Match; 5]>::try_from(entry.matches.as_slice()) else {
+            return Vec::new();
+        };
+        let Some(bot_guess) = solver.next_guess() else {
+            break;
+        };
+        moves.push(BotMove {
+            player_guess: entry.word.clone(),
+            bot_guess,
+            player_info_bits: information_gained(&entry.word, secret_word, solver.candidates()),
+            bot_info_bits: information_gained(bot_guess, secret_word, solver.candidates()),
+        });
+        solver.update(&entry.word, matches_);
+    }
+    moves
+}
+
+/// Runs the solver against every word in [`WORDS`], printing progress to
+/// stderr and the report to `out`. Failures are counted as one guess more
+/// than [`MAX_GUESSES`].
+#[allow(
+    clippy::missing_errors_doc,
+    clippy::cast_precision_loss,
+    clippy::missing_panics_doc
+)]
+pub fn print_profile(out: &mut impl Write) -> io::Result<()> {
+    let done = AtomicUsize::new(0);
+    let run = |word: &&str| {
+        let guesses = solve(word).unwrap_or(MAX_GUESSES + 1);
+        let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+        if done.is_multiple_of(100) || done == WORDS.len() {
+            eprint!("\rSolved {done}/{}", WORDS.len());
+        }
+        guesses
+    };
+
+    #[cfg(feature = "rayon")]
+    let mut results: Vec<usize> = {
+        use rayon::prelude::*;
+        WORDS.par_iter().map(run).collect()
+    };
+    #[cfg(not(feature = "rayon"))]
+    let mut results: Vec<usize> = WORDS.iter().map(run).collect();
+    eprintln!();
+
+    results.sort_unstable();
+    let n = results.len() as f64;
+    let mean = results.iter().sum::<usize>() as f64 / n;
+    let median = if results.len().is_multiple_of(2) {
+        (results[results.len() / 2 - 1] + results[results.len() / 2]) as f64 / 2.0
+    } else {
+        results[results.len() / 2] as f64
+    };
+    let std_dev = (results
+        .iter()
+        .map(|&g| (g as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n)
+        .sqrt();
+    let failures = results.iter().filter(|&&g| g > MAX_GUESSES).count();
+
+    writeln!(out, "Words:     {}", results.len())?;
+    writeln!(out, "Mean:      {mean:.3}")?;
+    writeln!(out, "Median:    {median}")?;
+    writeln!(out, "Std. dev.: {std_dev:.3}")?;
+    writeln!(out, "Min:       {}", results.first().unwrap())?;
+    writeln!(out, "Max:       {}", results.last().unwrap())?;
+    writeln!(
+        out,
+        "Failures:  {failures} ({:.2}%)",
+        failures as f64 / n * 100.0
+    )?;
+    writeln!(out)?;
+
+    let mut histogram = [0; MAX_GUESSES];
+    for &g in results.iter().filter(|&&g| g <= MAX_GUESSES) {
+        histogram[g - 1] += 1;
+    }
+    let max = histogram.iter().copied().max().unwrap_or(1).max(1);
+    for (i, count) in histogram.iter().enumerate() {
+        writeln!(
+            out,
+            "{} │{:<50} {count}",
+            i + 1,
+            "█".repeat(count * 50 / max)
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggestion_scores_a_sample_of_many_candidates() {
+        let candidates = &WORDS[..MAX_SCORED * 2];
+        let (word, _) = suggest_candidate(candidates, &AtomicBool::new(false)).unwrap();
+        let sampled: Vec<_> = candidates.iter().step_by(2).collect();
+        assert_eq!(sampled.len(), MAX_SCORED);
+        assert!(sampled.contains(&&word), "{word}");
+    }
+
+    #[test]
+    fn crane_entropy() {
+        // the ~5.2 bits sometimes quoted is against a different list; against
+        // the 2315 secret words it's the 5.74 bits most solvers report
+        let bits = compute_entropy("crane", WORDS);
+        assert!((bits - 5.74).abs() < 0.01, "{bits}");
+    }
+
+    #[test]
+    fn entropy_of_one_candidate_is_zero() {
+        assert!(compute_entropy("crane", &["slate"]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn entropy_of_candidates_split_evenly() {
+        // every candidate gives a different response
+        let candidates = ["crane", "slate", "pious", "crank"];
+        let bits = compute_entropy("crane", &candidates);
+        assert!((bits - 2.0).abs() < f64::EPSILON, "{bits}");
+    }
+}
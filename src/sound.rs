@@ -0,0 +1,57 @@
+//! Win and lose sound effects. Without the `sound` feature these do nothing.
+
+#[cfg(feature = "sound")]
+fn play(sample: &'static [u8]) -> Result<(), String> {
+    use std::{io::Cursor, sync::mpsc, thread};
+
+    use rodio::{Decoder, OutputStream, Sink};
+
+    // the output stream is not `Send` and has to outlive the playback, so
+    // everything lives in its own thread which reports back whether the
+    // audio device could be initialised
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let sink = OutputStream::try_default()
+            .map_err(|e| e.to_string())
+            .and_then(|(stream, handle)| {
+                let sink = Sink::try_new(&handle).map_err(|e| e.to_string())?;
+                let source = Decoder::new(Cursor::new(sample)).map_err(|e| e.to_string())?;
+                sink.append(source);
+                Ok((stream, sink))
+            });
+        match sink {
+            Ok((_stream, sink)) => {
+                tx.send(Ok(())).ok();
+                sink.sleep_until_end();
+            }
+            Err(e) => {
+                tx.send(Err(e)).ok();
+            }
+        }
+    });
+    rx.recv().unwrap_or(Ok(()))
+}
+
+#[cfg(feature = "sound")]
+#[allow(clippy::missing_errors_doc)]
+pub fn play_win_sound() -> Result<(), String> {
+    play(include_bytes!("../assets/win.wav"))
+}
+
+#[cfg(feature = "sound")]
+#[allow(clippy::missing_errors_doc)]
+pub fn play_lose_sound() -> Result<(), String> {
+    play(include_bytes!("../assets/lose.wav"))
+}
+
+#[cfg(not(feature = "sound"))]
+#[allow(clippy::missing_errors_doc, clippy::unnecessary_wraps)]
+pub fn play_win_sound() -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(not(feature = "sound"))]
+#[allow(clippy::missing_errors_doc, clippy::unnecessary_wraps)]
+pub fn play_lose_sound() -> Result<(), String> {
+    Ok(())
+}
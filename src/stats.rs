@@ -0,0 +1,1029 @@
+use std::{
+    collections::HashMap,
+    env, fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    config::Config,
+    game::{
+        check_word, deserialize_guess_history, is_pangram, letters_used, serialize_guess_history,
+        GameRecord, GuessEntry, GuessHistory, Match,
+    },
+    theme::EmojiStyle,
+};
+
+/// Most guesses a game allows.
+pub const MAX_GUESSES: usize = 6;
+
+/// Points scored by a game:
+///
+/// - `(7 - guesses used) * 100` for a win, from 600 for finding the word with
+///   the first guess down to 100 for finding it with the sixth,
+/// - 50 more for a win in hard mode, and 25 more in superhard mode, both
+///   adding up when played together,
+/// - 0 for a loss, whatever the mode.
+///
+/// ```
+/// use yawc::{
+///     config::Config,
+///     game::{GameRecord, GuessEntry, Match::*},
+///     stats::compute_score,
+/// };
+///
+/// let game = |guesses: usize, won: bool| GameRecord {
+///     secret_word: "crane".to_owned(),
+///     guesses: (1..=guesses)
+///         .map(|i| {
+///             let matches = if won && i == guesses { [Correct; 5] } else { [Incorrect; 5] };
+///             GuessEntry::new("crane".to_owned(), matches.to_vec())
+///         })
+///         .collect(),
+///     number: None,
+/// };
+/// let config = Config::default();
+/// let hard = Config { hard: true, ..Config::default() };
+/// let superhard = Config { superhard: true, ..Config::default() };
+///
+/// assert_eq!(compute_score(&game(1, true), &config), 600);
+/// assert_eq!(compute_score(&game(6, true), &config), 100);
+/// assert_eq!(compute_score(&game(6, true), &hard), 150);
+/// assert_eq!(compute_score(&game(6, true), &superhard), 125);
+/// assert_eq!(compute_score(&game(6, false), &hard), 0);
+/// assert_eq!(compute_score(&game(6, false), &config), 0);
+/// assert_eq!(compute_score(&game(6, false), &superhard), 0);
+/// assert_eq!(compute_score(&game(0, false), &config), 0);
+/// ```
+#[must_use]
+pub fn compute_score(record: &GameRecord, config: &Config) -> u32 {
+    let hard = if config.hard { 50 } else { 0 };
+    let superhard = if config.superhard { 25 } else { 0 };
+    match base_score(record.guesses_used()) {
+        0 => 0,
+        score => score + hard + superhard,
+    }
+}
+
+/// Score of a game won with `guesses` guesses, or lost if [`None`], without
+/// any bonus.
+fn base_score(guesses: Option<usize>) -> u32 {
+    match guesses {
+        Some(n @ 1..=MAX_GUESSES) => u32::try_from(MAX_GUESSES + 1 - n).unwrap_or(0) * 100,
+        _ => 0,
+    }
+}
+
+/// How the games played since the program started went, shown when the
+/// player quits with `Ctrl+C`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionSummary {
+    pub games_played: usize,
+    pub wins: usize,
+    /// Average number of guesses of the games won, `0` if none was.
+    pub average_guesses: f32,
+    /// Secret word of the game won with the fewest guesses, the first one if
+    /// several were, empty if no game was won.
+    pub best_word: String,
+    pub time_elapsed: Duration,
+}
+
+/// Sums up the finished games of `history`, played since `start_time`.
+///
+/// ```
+/// use std::time::Instant;
+///
+/// use yawc::{
+///     game::{GameRecord, GuessEntry, Match::*},
+///     stats::compute_session_summary,
+/// };
+///
+/// let game = |word: &str, guesses: usize, won: bool| GameRecord {
+///     secret_word: word.to_owned(),
+///     guesses: (1..=guesses)
+///         .map(|i| {
+///             let matches = if won && i == guesses { [Correct; 5] } else { [Incorrect; 5] };
+///             GuessEntry::new(word.to_owned(), matches.to_vec())
+///         })
+///         .collect(),
+///     number: None,
+/// };
+/// let history = [game("crane", 4, true), game("slate", 6, false), game("pious", 2, true)];
+///
+/// let summary = compute_session_summary(&history, Instant::now());
+/// assert_eq!(summary.games_played, 3);
+/// assert_eq!(summary.wins, 2);
+/// assert_eq!(summary.average_guesses, 3.0);
+/// assert_eq!(summary.best_word, "pious");
+///
+/// let summary = compute_session_summary(&[], Instant::now());
+/// assert_eq!(summary.wins, 0);
+/// assert_eq!(summary.average_guesses, 0.0);
+/// assert_eq!(summary.best_word, "");
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn compute_session_summary(history: &[GameRecord], start_time: Instant) -> SessionSummary {
+    let wins: Vec<_> = history
+        .iter()
+        .filter_map(|r| Some((r, r.guesses_used()?)))
+        .collect();
+    let total_guesses: usize = wins.iter().map(|(_, n)| n).sum();
+    SessionSummary {
+        games_played: history.len(),
+        wins: wins.len(),
+        average_guesses: if wins.is_empty() {
+            0.0
+        } else {
+            total_guesses as f32 / wins.len() as f32
+        },
+        best_word: wins
+            .iter()
+            .min_by_key(|(_, n)| *n)
+            .map(|(r, _)| r.secret_word.clone())
+            .unwrap_or_default(),
+        time_elapsed: start_time.elapsed(),
+    }
+}
+
+impl fmt::Display for SessionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.time_elapsed.as_secs();
+        write!(
+            f,
+            "Games played: {}, won: {}, in {}:{:02}",
+            self.games_played,
+            self.wins,
+            secs / 60,
+            secs % 60
+        )?;
+        if self.wins > 0 {
+            write!(
+                f,
+                "\nAverage guesses: {:.1}, best word: {}",
+                self.average_guesses, self.best_word
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A finished game as stored in the scores file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ScoreRecord {
+    /// When the game ended, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub word: String,
+    /// Number of guesses it took to win, `None` if the game was lost.
+    pub guesses: Option<usize>,
+    /// See [`compute_score`].
+    pub score: u32,
+    /// Whether the guesses tried all 26 letters, see [`is_pangram`].
+    pub pangram: bool,
+    /// The guesses made, empty for games recorded before they were kept, or
+    /// of words other than 5 ASCII letters.
+    #[serde(skip)]
+    pub history: GuessHistory,
+    /// Number of secret words chained up to this one with `--wordchain`,
+    /// `None` for other games.
+    pub chain: Option<usize>,
+    /// Whether the game was lost by revealing the word with `Ctrl+R`, rather
+    /// than by running out of guesses.
+    pub revealed: bool,
+    /// Number of the puzzle, see [`GameRecord::number`].
+    pub number: Option<u32>,
+}
+
+impl ScoreRecord {
+    /// A record of `game` ending now, not part of any [`chain`](Self::chain)
+    /// nor [`revealed`](Self::revealed).
+    #[must_use]
+    pub fn now(game: &GameRecord, config: &Config) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            word: game.secret_word.clone(),
+            guesses: game.guesses_used(),
+            score: compute_score(game, config),
+            pangram: is_pangram(letters_used(&game.guesses)),
+            history: game.guesses.clone(),
+            chain: None,
+            revealed: false,
+            number: game.number,
+        }
+    }
+
+    /// The day the game ended on as `YYYY-MM-DD`, in UTC.
+    #[must_use]
+    pub fn date(&self) -> String {
+        let (y, m, d) = civil_from_days(self.timestamp / 86400);
+        format!("{y:04}-{m:02}-{d:02}")
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let timestamp = fields.next()?.parse().ok()?;
+        let word = fields.next()?.to_owned();
+        let guesses = match fields.next()? {
+            "-" => None,
+            n => Some(n.parse().ok()?),
+        };
+        // older lines have no score, nor anything to tell superhard games by
+        let score = match fields.next() {
+            Some(score) => score.parse().ok()?,
+            None => base_score(guesses),
+        };
+        let pangram = fields.next() == Some("1");
+        let history = fields
+            .next()
+            .and_then(deserialize_guess_history)
+            .unwrap_or_default();
+        let chain = fields.next().and_then(|chain| chain.parse().ok());
+        let revealed = fields.next() == Some("1");
+        let number = fields.next().and_then(|number| number.parse().ok());
+        Some(Self {
+            timestamp,
+            word,
+            guesses,
+            score,
+            pangram,
+            history,
+            chain,
+            revealed,
+            number,
+        })
+    }
+}
+
+impl fmt::Display for ScoreRecord {
+    /// The line of the scores file holding this record.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.guesses {
+            Some(n) => write!(f, "{}\t{}\t{n}", self.timestamp, self.word)?,
+            None => write!(f, "{}\t{}\t-", self.timestamp, self.word)?,
+        }
+        write!(f, "\t{}\t{}\t", self.score, u8::from(self.pangram))?;
+        let compact = [&self.word]
+            .into_iter()
+            .chain(self.history.iter().map(|g| &g.word))
+            .all(|w| w.len() == 5 && w.bytes().all(|b| b.is_ascii_lowercase()));
+        if compact {
+            write!(f, "{}", serialize_guess_history(&self.history))?;
+        }
+        let optional = [
+            self.chain.map(|n| n.to_string()).unwrap_or_default(),
+            if self.revealed { "1" } else { "" }.to_owned(),
+            self.number.map(|n| n.to_string()).unwrap_or_default(),
+        ];
+        // left out from the last one that holds something, like on older lines
+        let kept = optional.iter().rposition(|field| !field.is_empty());
+        for field in &optional[..kept.map_or(0, |i| i + 1)] {
+            write!(f, "\t{field}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts days since the Unix epoch to a `(year, month, day)` date in the
+/// proleptic Gregorian calendar.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + u64::from(m <= 2);
+    (y, m, d)
+}
+
+/// Where scores are kept: `$XDG_DATA_HOME/yawc/scores`, falling back to
+/// `~/.local/share/yawc/scores`.
+#[must_use]
+pub fn scores_path() -> Option<PathBuf> {
+    let data = env::var_os("XDG_DATA_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))?;
+    Some(data.join("yawc").join("scores"))
+}
+
+/// Reads all the records from the scores file. A missing file has no
+/// records, malformed lines are skipped.
+#[allow(clippy::missing_errors_doc)]
+pub fn load_scores() -> io::Result<Vec<ScoreRecord>> {
+    let Some(path) = scores_path() else {
+        return Ok(Vec::new());
+    };
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.lines().filter_map(ScoreRecord::parse).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Adds `record` to the end of the scores file, creating it if needed.
+#[allow(clippy::missing_errors_doc)]
+pub fn append_score(record: &ScoreRecord) -> io::Result<()> {
+    let path = scores_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{record}")
+}
+
+/// A game left unfinished, kept in the [`snapshot_path`] file as a line
+/// starting with `snapshot` so `yawc resume` can pick it up again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub word: String,
+    pub guesses: Vec<String>,
+    /// What was typed in for the next guess.
+    pub current_guess: String,
+}
+
+impl Snapshot {
+    #[must_use]
+    pub fn guesses_remaining(&self) -> usize {
+        MAX_GUESSES.saturating_sub(self.guesses.len())
+    }
+
+    /// Whether the game can be picked up again: it's neither won nor out of
+    /// guesses, whatever was typed in for the next one.
+    #[must_use]
+    pub fn is_in_progress(&self) -> bool {
+        self.guesses_remaining() > 0 && !self.guesses.contains(&self.word)
+    }
+
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.strip_prefix("snapshot\t")?.split('\t');
+        let word = fields.next()?.to_owned();
+        let guesses = match fields.next()? {
+            "" => Vec::new(),
+            guesses => deserialize_guess_history(guesses).map_or_else(
+                || guesses.split(',').map(str::to_owned).collect(),
+                |history| history.into_iter().map(|g| g.word).collect(),
+            ),
+        };
+        let current_guess = fields.next()?.to_owned();
+        Some(Self {
+            word,
+            guesses,
+            current_guess,
+        })
+    }
+}
+
+impl fmt::Display for Snapshot {
+    /// The line of the snapshot file holding this snapshot. Games of 5-letter
+    /// ASCII words have their guesses kept as a [`serialize_guess_history`]
+    /// string, others as a list of words separated by commas.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let compact = [&self.word]
+            .into_iter()
+            .chain(&self.guesses)
+            .all(|w| w.len() == 5 && w.bytes().all(|b| b.is_ascii_lowercase()));
+        let guesses = if compact {
+            let history: Vec<_> = self
+                .guesses
+                .iter()
+                .map(|g| GuessEntry::new(g.clone(), check_word(&self.word, g).to_vec()))
+                .collect();
+            serialize_guess_history(&history)
+        } else {
+            self.guesses.join(",")
+        };
+        write!(
+            f,
+            "snapshot\t{}\t{}\t{}",
+            self.word, guesses, self.current_guess
+        )
+    }
+}
+
+/// Where an unfinished game is kept for `yawc resume`: `snapshot`, next to
+/// the scores file.
+#[must_use]
+pub fn snapshot_path() -> Option<PathBuf> {
+    Some(scores_path()?.with_file_name("snapshot"))
+}
+
+/// The saved snapshot, if it's of a game still in progress.
+#[allow(clippy::missing_errors_doc)]
+pub fn load_snapshot() -> io::Result<Option<Snapshot>> {
+    let Some(path) = snapshot_path() else {
+        return Ok(None);
+    };
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents
+            .lines()
+            .find_map(Snapshot::parse)
+            .filter(Snapshot::is_in_progress)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Replaces any saved snapshot with `snapshot`. It's written next to the
+/// snapshot file first and then moved over it, so the old one stays whole if
+/// the writing fails halfway.
+#[allow(clippy::missing_errors_doc)]
+pub fn save_snapshot(snapshot: &Snapshot) -> io::Result<()> {
+    let path = snapshot_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("partial");
+    fs::write(&partial, format!("{snapshot}\n"))?;
+    fs::rename(partial, path)
+}
+
+/// Removes the saved snapshot, if there's one.
+#[allow(clippy::missing_errors_doc)]
+pub fn clear_snapshot() -> io::Result<()> {
+    let Some(path) = snapshot_path() else {
+        return Ok(());
+    };
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// A finished tournament, kept as a line of the scores file starting with
+/// `tournament`, for the leaderboard of its seed. [`load_scores`] skips it
+/// like any other line that isn't a score.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentRecord {
+    /// When the tournament ended, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub seed: u64,
+    /// Total guesses used, see [`TournamentGame::score`].
+    ///
+    /// [`TournamentGame::score`]: crate::tournament::TournamentGame::score
+    pub score: usize,
+}
+
+impl TournamentRecord {
+    /// A record of the tournament of `seed` ending now with `score`.
+    #[must_use]
+    pub fn now(seed: u64, score: usize) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            seed,
+            score,
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.strip_prefix("tournament\t")?.split('\t');
+        Some(Self {
+            timestamp: fields.next()?.parse().ok()?,
+            seed: fields.next()?.parse().ok()?,
+            score: fields.next()?.parse().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for TournamentRecord {
+    /// The line of the scores file holding this record.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tournament\t{}\t{}\t{}",
+            self.timestamp, self.seed, self.score
+        )
+    }
+}
+
+/// Adds `record` to the end of the scores file, creating it if needed.
+#[allow(clippy::missing_errors_doc)]
+pub fn append_tournament(record: &TournamentRecord) -> io::Result<()> {
+    let path = scores_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{record}")
+}
+
+/// The tournaments of `seed` in the scores file, best first, so with the
+/// fewest guesses and the earliest of those that tie.
+#[allow(clippy::missing_errors_doc)]
+pub fn tournament_leaderboard(seed: u64) -> io::Result<Vec<TournamentRecord>> {
+    let Some(path) = scores_path() else {
+        return Ok(Vec::new());
+    };
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(leaderboard(&contents, seed)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// The tournaments of `seed` in the lines of a scores file, best first.
+fn leaderboard(contents: &str, seed: u64) -> Vec<TournamentRecord> {
+    let mut records: Vec<_> = contents
+        .lines()
+        .filter_map(TournamentRecord::parse)
+        .filter(|r| r.seed == seed)
+        .collect();
+    records.sort_by_key(|r| (r.score, r.timestamp));
+    records
+}
+
+/// `matches` as one base-3 digit per letter: `0` for incorrect, `1` for
+/// misplaced and `2` for correct.
+///
+/// ```
+/// use yawc::{game::Match::*, stats::pattern_key};
+///
+/// assert_eq!(pattern_key([Correct, Misplaced, Incorrect, Incorrect, Correct]), [2, 1, 0, 0, 2]);
+/// ```
+#[must_use]
+pub fn pattern_key(matches: [Match; 5]) -> [u8; 5] {
+    matches.map(|m| match m {
+        Match::Incorrect => 0,
+        Match::Misplaced => 1,
+        Match::Correct => 2,
+    })
+}
+
+/// Inverse of [`pattern_key`].
+fn pattern_matches(key: [u8; 5]) -> [Match; 5] {
+    key.map(|digit| match digit {
+        2 => Match::Correct,
+        1 => Match::Misplaced,
+        _ => Match::Incorrect,
+    })
+}
+
+/// How many times each match pattern came up, keyed by [`pattern_key`].
+///
+/// ```
+/// use yawc::{
+///     game::{GuessEntry, Match::*},
+///     stats::{pattern_key, MatchHistory, ScoreRecord},
+/// };
+///
+/// let record = |history: Vec<GuessEntry>| ScoreRecord {
+///     timestamp: 0,
+///     word: "crane".to_owned(),
+///     guesses: Some(history.len()),
+///     score: 0,
+///     pangram: false,
+///     history,
+///     chain: None,
+///     revealed: false,
+///     number: None,
+/// };
+/// let miss = || GuessEntry::new("pious".to_owned(), vec![Incorrect; 5]);
+/// let win = || GuessEntry::new("crane".to_owned(), vec![Correct; 5]);
+///
+/// let history = MatchHistory::from_records(&[record(vec![miss(), win()]), record(vec![miss()])]);
+/// assert_eq!(history.0[&pattern_key([Incorrect; 5])], 2);
+/// assert_eq!(history.most_frequent(10), [([Incorrect; 5], 2), ([Correct; 5], 1)]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatchHistory(pub HashMap<[u8; 5], u32>);
+
+impl MatchHistory {
+    /// Counts the patterns of all the guesses of `records`. Guesses of words
+    /// other than 5 letters long aren't counted.
+    #[must_use]
+    pub fn from_records(records: &[ScoreRecord]) -> Self {
+        let mut counts = HashMap::new();
+        for guess in records.iter().flat_map(|r| &r.history) {
+            if let Ok(matches) = <[Match; 5]>::try_from(guess.matches.as_slice()) {
+                *counts.entry(pattern_key(matches)).or_insert(0) += 1;
+            }
+        }
+        Self(counts)
+    }
+
+    /// The `n` most frequent patterns, most frequent first.
+    #[must_use]
+    pub fn most_frequent(&self, n: usize) -> Vec<([Match; 5], u32)> {
+        let mut counts: Vec<_> = self.0.iter().map(|(&key, &count)| (key, count)).collect();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+            .into_iter()
+            .take(n)
+            .map(|(key, count)| (pattern_matches(key), count))
+            .collect()
+    }
+}
+
+/// Prints the 10 most frequent match patterns of the guesses in the scores
+/// file, for `yawc analysis --match-distribution`.
+#[allow(clippy::missing_errors_doc, clippy::cast_precision_loss)]
+pub fn print_match_distribution(out: &mut impl Write) -> io::Result<()> {
+    let history = MatchHistory::from_records(&load_scores()?);
+    let total: u32 = history.0.values().sum();
+    if total == 0 {
+        return writeln!(out, "No guesses recorded yet.");
+    }
+    writeln!(out, "Most frequent match patterns")?;
+    writeln!(out, "┌────────────┬───────┬────────┐")?;
+    for (matches, count) in history.most_frequent(10) {
+        let squares: String = matches
+            .iter()
+            .map(|&m| EmojiStyle::Wordle.symbol(m))
+            .collect();
+        let percent = f64::from(count) / f64::from(total) * 100.0;
+        writeln!(out, "│ {squares} │ {count:>5} │ {percent:>5.1}% │")?;
+    }
+    writeln!(out, "└────────────┴───────┴────────┘")?;
+    writeln!(out, "Guesses recorded: {total}")
+}
+
+/// Renders score records for `yawc scores`.
+pub trait ScoreFormatter {
+    fn format(&self, records: &[ScoreRecord]) -> String;
+}
+
+/// Box table followed by a summary, for people.
+pub struct PlainFormatter;
+
+/// A JSON array of objects with `timestamp`, `word`, `guesses`, `score`,
+/// `pangram`, `chain`, `revealed` and `number` keys, `guesses` being `null`
+/// for lost games, `chain` for games not played with `--wordchain` and
+/// `number` for games without a puzzle number.
+pub struct JsonFormatter;
+
+/// RFC 4180 CSV with a
+/// `timestamp,word,guesses,score,pangram,chain,revealed,number` header,
+/// `guesses` being empty for lost games, `chain` for games not played with
+/// `--wordchain` and `number` for games without a puzzle number.
+pub struct CsvFormatter;
+
+impl ScoreFormatter for PlainFormatter {
+    fn format(&self, records: &[ScoreRecord]) -> String {
+        if records.is_empty() {
+            return "No games played yet.\n".to_owned();
+        }
+        // as wide as the longest word, 7 letters in `--mode plus`
+        let width = records
+            .iter()
+            .map(|r| r.word.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Word".len());
+        let line = "─".repeat(width + 2);
+        let mut out = String::new();
+        out.push_str(&format!("┌────────────┬{line}┬─────┬───────┐\n"));
+        out.push_str(&format!(
+            "│ Date       │ {:<width$} │     │ Score │\n",
+            "Word"
+        ));
+        out.push_str(&format!("├────────────┼{line}┼─────┼───────┤\n"));
+        for r in records {
+            let result = r.guesses.map_or_else(
+                || format!("X/{MAX_GUESSES}"),
+                |n| format!("{n}/{MAX_GUESSES}"),
+            );
+            out.push_str(&format!(
+                "│ {} │ {:<width$} │ {result} │ {:>5} │\n",
+                r.date(),
+                r.word,
+                r.score
+            ));
+        }
+        out.push_str(&format!("└────────────┴{line}┴─────┴───────┘\n"));
+
+        let wins: Vec<_> = records.iter().filter_map(|r| r.guesses).collect();
+        out.push_str(&format!(
+            "Played: {}, won: {:.0}%, revealed: {}",
+            records.len(),
+            wins.len() as f64 / records.len() as f64 * 100.0,
+            records.iter().filter(|r| r.revealed).count()
+        ));
+        if !wins.is_empty() {
+            out.push_str(&format!(
+                ", average guesses: {:.2}",
+                wins.iter().sum::<usize>() as f64 / wins.len() as f64
+            ));
+        }
+        out.push_str(&format!(
+            ", pangrams achieved: {}",
+            records.iter().filter(|r| r.pangram).count()
+        ));
+        if let Some(chain) = records.iter().filter_map(|r| r.chain).max() {
+            out.push_str(&format!(", longest word chain: {chain}"));
+        }
+        out.push('\n');
+        out
+    }
+}
+
+impl ScoreFormatter for JsonFormatter {
+    fn format(&self, records: &[ScoreRecord]) -> String {
+        // plain numbers, strings and booleans can always be written
+        serde_json::to_string(records).expect("score records are valid JSON") + "\n"
+    }
+}
+
+impl ScoreFormatter for CsvFormatter {
+    fn format(&self, records: &[ScoreRecord]) -> String {
+        let mut out =
+            String::from("timestamp,word,guesses,score,pangram,chain,revealed,number\r\n");
+        for r in records {
+            let guesses = r.guesses.map(|n| n.to_string()).unwrap_or_default();
+            let chain = r.chain.map(|n| n.to_string()).unwrap_or_default();
+            let number = r.number.map(|n| n.to_string()).unwrap_or_default();
+            out.push_str(&format!(
+                "{},{},{guesses},{},{},{chain},{},{number}\r\n",
+                r.timestamp,
+                csv_field(&r.word),
+                r.score,
+                r.pangram,
+                r.revealed
+            ));
+        }
+        out
+    }
+}
+
+/// Quotes `field` if it has any characters that are special in CSV.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Output format of `yawc scores`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Plain,
+    Json,
+    Csv,
+}
+
+impl OutputFormat {
+    #[must_use]
+    pub fn formatter(self) -> &'static dyn ScoreFormatter {
+        match self {
+            OutputFormat::Plain => &PlainFormatter,
+            OutputFormat::Json => &JsonFormatter,
+            OutputFormat::Csv => &CsvFormatter,
+        }
+    }
+}
+
+/// Prints the scores file in the given format.
+#[allow(clippy::missing_errors_doc)]
+pub fn print_scores(out: &mut impl Write, format: OutputFormat) -> io::Result<()> {
+    let records = load_scores()?;
+    write!(out, "{}", format.formatter().format(&records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A game of `guesses` guesses, the last of them right if `won`.
+    fn game(guesses: usize, won: bool) -> GameRecord {
+        GameRecord {
+            secret_word: "crane".to_owned(),
+            guesses: (1..=guesses)
+                .map(|i| {
+                    let m = if won && i == guesses {
+                        Match::Correct
+                    } else {
+                        Match::Incorrect
+                    };
+                    GuessEntry::new("crane".to_owned(), vec![m; 5])
+                })
+                .collect(),
+            number: None,
+        }
+    }
+
+    fn hard() -> Config {
+        Config {
+            hard: true,
+            ..Config::default()
+        }
+    }
+
+    fn superhard() -> Config {
+        Config {
+            superhard: true,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn first_guess_win() {
+        assert_eq!(compute_score(&game(1, true), &Config::default()), 600);
+        assert_eq!(compute_score(&game(1, true), &hard()), 650);
+        assert_eq!(compute_score(&game(1, true), &superhard()), 625);
+        let both = Config {
+            superhard: true,
+            ..hard()
+        };
+        assert_eq!(compute_score(&game(1, true), &both), 675);
+    }
+
+    #[test]
+    fn last_guess_win() {
+        assert_eq!(
+            compute_score(&game(MAX_GUESSES, true), &Config::default()),
+            100
+        );
+        assert_eq!(compute_score(&game(MAX_GUESSES, true), &hard()), 150);
+        assert_eq!(compute_score(&game(MAX_GUESSES, true), &superhard()), 125);
+    }
+
+    #[test]
+    fn every_win_scores_100_less_than_with_one_guess_fewer() {
+        for guesses in 2..=MAX_GUESSES {
+            let score = |n| compute_score(&game(n, true), &Config::default());
+            assert_eq!(score(guesses - 1) - score(guesses), 100);
+        }
+    }
+
+    #[test]
+    fn loss_scores_nothing() {
+        assert_eq!(
+            compute_score(&game(MAX_GUESSES, false), &Config::default()),
+            0
+        );
+        assert_eq!(compute_score(&game(MAX_GUESSES, false), &hard()), 0);
+        assert_eq!(compute_score(&game(MAX_GUESSES, false), &superhard()), 0);
+    }
+
+    #[test]
+    fn no_guesses_scores_nothing() {
+        assert_eq!(compute_score(&game(0, false), &Config::default()), 0);
+        assert_eq!(compute_score(&game(0, false), &superhard()), 0);
+    }
+
+    #[test]
+    fn more_guesses_than_allowed_scores_nothing() {
+        assert_eq!(
+            compute_score(&game(MAX_GUESSES + 1, true), &Config::default()),
+            0
+        );
+    }
+
+    fn snapshot(guesses: &[&str], current_guess: &str) -> Snapshot {
+        Snapshot {
+            word: "crane".to_owned(),
+            guesses: guesses.iter().map(|&g| g.to_owned()).collect(),
+            current_guess: current_guess.to_owned(),
+        }
+    }
+
+    #[test]
+    fn unfinished_game_is_in_progress_whatever_is_typed() {
+        assert!(snapshot(&["slate", "pious"], "").is_in_progress());
+        assert!(snapshot(&["slate", "pious"], "cr").is_in_progress());
+        assert!(snapshot(&[], "").is_in_progress());
+    }
+
+    #[test]
+    fn finished_game_is_not_in_progress() {
+        assert!(!snapshot(&["slate", "crane"], "").is_in_progress());
+        assert!(!snapshot(&["slate"; MAX_GUESSES], "").is_in_progress());
+        assert_eq!(snapshot(&["slate"; MAX_GUESSES], "").guesses_remaining(), 0);
+    }
+
+    #[test]
+    fn chain_is_kept_in_the_scores_file() {
+        let record = ScoreRecord {
+            chain: Some(3),
+            ..ScoreRecord::now(&game(4, true), &Config::default())
+        };
+        assert_eq!(
+            ScoreRecord::parse(&record.to_string()),
+            Some(record.clone())
+        );
+
+        let unchained = ScoreRecord {
+            chain: None,
+            ..record
+        };
+        assert_eq!(ScoreRecord::parse(&unchained.to_string()), Some(unchained));
+    }
+
+    #[test]
+    fn chain_of_a_game_without_history_is_kept() {
+        let record = ScoreRecord {
+            word: "crâne".to_owned(),
+            chain: Some(2),
+            ..ScoreRecord::now(&game(1, true), &Config::default())
+        };
+        let parsed = ScoreRecord::parse(&record.to_string()).unwrap();
+        assert!(parsed.history.is_empty());
+        assert_eq!(parsed.chain, Some(2));
+    }
+
+    #[test]
+    fn number_is_kept_in_the_scores_file() {
+        let game = GameRecord {
+            number: Some(1234),
+            ..game(3, true)
+        };
+        let record = ScoreRecord::now(&game, &Config::default());
+        assert_eq!(record.number, Some(1234));
+        assert!(record.to_string().ends_with("\t\t\t1234"));
+        assert_eq!(ScoreRecord::parse(&record.to_string()), Some(record));
+    }
+
+    #[test]
+    fn tournament_record_round_trips() {
+        let record = TournamentRecord::now(42, 31);
+        assert_eq!(TournamentRecord::parse(&record.to_string()), Some(record));
+        assert_eq!(TournamentRecord::parse("snapshot\tcrane\t\t"), None);
+    }
+
+    #[test]
+    fn leaderboard_is_of_the_seed_best_first() {
+        let record = |timestamp, seed, score| TournamentRecord {
+            timestamp,
+            seed,
+            score,
+        };
+        let contents = [
+            record(1, 42, 35).to_string(),
+            "1792122905\tgoing\t1\t600\t0\tZ29pbmfy".to_owned(),
+            record(2, 7, 20).to_string(),
+            record(3, 42, 28).to_string(),
+            record(4, 42, 35).to_string(),
+        ]
+        .join("\n");
+        assert_eq!(
+            leaderboard(&contents, 42),
+            [record(3, 42, 28), record(1, 42, 35), record(4, 42, 35)]
+        );
+        assert!(leaderboard(&contents, 1).is_empty());
+        // and the scores are still read as they were
+        assert_eq!(contents.lines().filter_map(ScoreRecord::parse).count(), 1);
+    }
+
+    #[test]
+    fn reveal_is_kept_apart_from_losses() {
+        let lost = ScoreRecord::now(&game(2, false), &Config::default());
+        let revealed = ScoreRecord {
+            revealed: true,
+            ..lost.clone()
+        };
+        assert_eq!(
+            ScoreRecord::parse(&revealed.to_string()),
+            Some(revealed.clone())
+        );
+        assert_eq!(ScoreRecord::parse(&lost.to_string()), Some(lost.clone()));
+
+        let summary = PlainFormatter.format(&[lost, revealed]);
+        assert!(summary.contains("won: 0%, revealed: 1"), "{summary}");
+    }
+
+    #[test]
+    fn json_escapes_control_characters() {
+        let record = ScoreRecord {
+            word: "cr\u{1b}ne".to_owned(),
+            ..ScoreRecord::now(&game(2, false), &Config::default())
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&JsonFormatter.format(&[record])).unwrap();
+        assert_eq!(json[0]["word"], "cr\u{1b}ne");
+        assert_eq!(json[0]["guesses"], serde_json::Value::Null);
+        assert!(json[0].get("history").is_none());
+    }
+
+    #[test]
+    fn plain_table_fits_longer_words() {
+        let game = GameRecord {
+            secret_word: "example".to_owned(),
+            ..game(3, true)
+        };
+        let table = PlainFormatter.format(&[ScoreRecord::now(&game, &Config::default())]);
+        let widths: Vec<_> = table
+            .lines()
+            .take_while(|l| !l.starts_with("Played"))
+            .map(|l| l.chars().count())
+            .collect();
+        assert_eq!(widths.len(), 5);
+        assert!(widths.iter().all(|&w| w == widths[0]), "{table}");
+        assert!(table.contains("│ example │ 3/6 │"), "{table}");
+    }
+}
@@ -0,0 +1,227 @@
+use std::{env, fmt, io};
+
+use crossterm::{
+    cursor, queue,
+    style::{self, Stylize},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::raw::Terminal;
+
+/// A message for the status bar, coloured according to its kind.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatusMessage {
+    Info(String),
+    Error(String),
+    Success(String),
+}
+
+impl StatusMessage {
+    #[must_use]
+    pub fn text(&self) -> &str {
+        match self {
+            StatusMessage::Info(s) | StatusMessage::Error(s) | StatusMessage::Success(s) => s,
+        }
+    }
+
+    /// A message of the same kind saying `text` instead.
+    #[must_use]
+    pub fn with_text(&self, text: String) -> Self {
+        match self {
+            StatusMessage::Info(_) => StatusMessage::Info(text),
+            StatusMessage::Error(_) => StatusMessage::Error(text),
+            StatusMessage::Success(_) => StatusMessage::Success(text),
+        }
+    }
+
+    /// Width of the message on screen, used to center it. Hyperlink escape
+    /// sequences take no room.
+    #[must_use]
+    pub fn parts_len(&self) -> u16 {
+        strip_links(self.text())
+            .width()
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+
+    /// The message split into lines of at most `max_width` columns, see
+    /// [`wrap_text`], each of the same kind.
+    #[must_use]
+    pub fn wrap(&self, max_width: u16) -> Vec<StatusMessage> {
+        wrap_text(self.text(), max_width)
+            .into_iter()
+            .map(|line| self.with_text(line))
+            .collect()
+    }
+}
+
+/// Splits `s` into lines at most `max_width` columns wide, breaking it at
+/// spaces. A word wider than that gets a line of its own, and links take only
+/// the room of their text.
+///
+/// ```
+/// use yawc::status::wrap_text;
+///
+/// assert_eq!(
+///     wrap_text("You won! The word was CRANE.", 12),
+///     ["You won! The", "word was", "CRANE."],
+/// );
+/// assert_eq!(wrap_text("short", 12), ["short"]);
+/// assert_eq!(wrap_text("unbreakable", 4), ["unbreakable"]);
+/// ```
+#[must_use]
+pub fn wrap_text(s: &str, max_width: u16) -> Vec<String> {
+    let max_width = usize::from(max_width);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in s.split_whitespace() {
+        let width = strip_links(word).width();
+        if !line.is_empty() && line_width + 1 + width > max_width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if !line.is_empty() {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += width;
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Frames of the spinner shown while waiting on something slow.
+pub const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+const OSC8_START: &str = "\x1b]8;;";
+const OSC8_END: &str = "\x1b\\";
+
+/// Wraps `text` in an OSC 8 escape sequence making it a hyperlink to `url` in
+/// terminals that support them.
+///
+/// ```
+/// use yawc::status::osc8_link;
+///
+/// assert_eq!(
+///     osc8_link("https://example.com", "text"),
+///     "\x1b]8;;https://example.com\x1b\\text\x1b]8;;\x1b\\",
+/// );
+/// ```
+#[must_use]
+pub fn osc8_link(url: &str, text: &str) -> String {
+    format!("{OSC8_START}{url}{OSC8_END}{text}{OSC8_START}{OSC8_END}")
+}
+
+/// An OSC 52 escape sequence asking the terminal to put `text` in the
+/// clipboard. Terminals that don't support it ignore it.
+///
+/// ```
+/// use yawc::status::osc52_copy;
+///
+/// assert_eq!(osc52_copy("1234"), "\x1b]52;c;MTIzNA==\x07");
+/// assert_eq!(osc52_copy("12345"), "\x1b]52;c;MTIzNDU=\x07");
+/// assert_eq!(osc52_copy("123456"), "\x1b]52;c;MTIzNDU2\x07");
+/// ```
+#[must_use]
+pub fn osc52_copy(text: &str) -> String {
+    const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::new();
+    for chunk in text.as_bytes().chunks(3) {
+        let n = chunk.iter().fold(0u32, |n, &b| n << 8 | u32::from(b)) << (8 * (3 - chunk.len()));
+        for i in 0..4 {
+            encoded.push(if i <= chunk.len() {
+                char::from(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize])
+            } else {
+                '='
+            });
+        }
+    }
+    format!("\x1b]52;c;{encoded}\x07")
+}
+
+/// Draws `[seed: 12345678]` at `col` and `row`, for the seed of a game to be
+/// shared.
+#[allow(clippy::missing_errors_doc)]
+pub fn draw_seed_badge(term: &mut Terminal, seed: u64, col: u16, row: u16) -> io::Result<()> {
+    queue!(
+        term,
+        cursor::SavePosition,
+        cursor::MoveTo(col, row),
+        style::PrintStyledContent(format!("[seed: {seed}]").dark_grey()),
+        cursor::RestorePosition,
+    )?;
+    io::Write::flush(term)
+}
+
+/// `text` without the OSC 8 sequences around its links.
+fn strip_links(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(OSC8_START) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + OSC8_START.len()..];
+        match rest.find(OSC8_END) {
+            Some(end) => rest = &rest[end + OSC8_END.len()..],
+            None => rest = "",
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Whether the terminal is known to support OSC 8 hyperlinks: iTerm2, WezTerm
+/// and VTE-based terminals since VTE 0.50. Others may print the escape
+/// sequences as garbage, so they don't get any.
+#[must_use]
+pub fn supports_hyperlinks() -> bool {
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+    let vte_version = env::var("VTE_VERSION")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(0);
+    matches!(term_program.as_str(), "iTerm.app" | "WezTerm") || vte_version >= 5000
+}
+
+impl fmt::Display for StatusMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusMessage::Info(s) => write!(f, "{}", s.as_str().white()),
+            StatusMessage::Error(s) => write!(f, "{}", s.as_str().red()),
+            StatusMessage::Success(s) => write!(f, "{}", s.as_str().green()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osc8_link_format() {
+        assert_eq!(
+            osc8_link("https://example.com/a?b=c", "the word"),
+            "\x1b]8;;https://example.com/a?b=c\x1b\\the word\x1b]8;;\x1b\\",
+        );
+    }
+
+    #[test]
+    fn osc8_link_with_empty_text() {
+        assert_eq!(
+            osc8_link("https://example.com", ""),
+            "\x1b]8;;https://example.com\x1b\\\x1b]8;;\x1b\\",
+        );
+    }
+
+    #[test]
+    fn osc8_link_is_stripped_back_to_its_text() {
+        assert_eq!(
+            strip_links(&osc8_link("https://example.com", "text")),
+            "text"
+        );
+        assert_eq!(strip_links(&osc8_link("https://example.com", "")), "");
+    }
+}
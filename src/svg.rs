@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use crossterm::style::Color;
+
+use crate::{
+    game::{GameRecord, Match},
+    stats::MAX_GUESSES,
+    theme::{color_to_rgb, Theme},
+};
+
+/// Side of a tile, in pixels.
+const TILE: usize = 62;
+/// Space between two tiles.
+const GAP: usize = 2;
+/// Width of the image, unless the words are too long for it.
+const WIDTH: usize = 350;
+/// Height of the `yawc 3/6` line above the tiles.
+const HEADER: usize = 50;
+/// Space around the tiles.
+const MARGIN: usize = 16;
+
+/// Background of the image, as on Wordle.
+const BACKGROUND: &str = "#121213";
+/// Tiles of incorrect letters, which have no colour in the terminal.
+const INCORRECT_TILE: &str = "#3a3a3c";
+
+/// The guesses of `record` as an SVG image like the one Wordle shares: a
+/// `yawc 3/6` header, `yawc #1234 3/6` with a puzzle number, over a row of
+/// coloured tiles per guess, each with its letter in the middle. The secret word isn't in it, unless it was
+/// guessed.
+///
+/// ```
+/// use yawc::{
+///     game::{GameRecord, GuessEntry, Match::*},
+///     svg::generate_wordle_image,
+///     theme::CLASSIC,
+/// };
+///
+/// let record = GameRecord {
+///     secret_word: "crane".to_owned(),
+///     guesses: vec![
+///         GuessEntry::new("slate".to_owned(), vec![Incorrect, Incorrect, Correct, Incorrect, Misplaced]),
+///         GuessEntry::new("crane".to_owned(), vec![Correct; 5]),
+///     ],
+///     number: None,
+/// };
+/// let svg = generate_wordle_image(&record, &CLASSIC);
+/// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+/// assert!(svg.contains("viewBox=\"0 0 350 "));
+/// assert_eq!(svg.matches("<rect").count(), 1 + 10);
+/// assert_eq!(svg.matches("width=\"62\" height=\"62\"").count(), 10);
+/// assert!(svg.contains(">yawc 2/6</text>"));
+/// ```
+#[must_use]
+pub fn generate_wordle_image(record: &GameRecord, theme: &Theme) -> String {
+    let word_len = record.guesses.first().map_or(5, |g| g.word.chars().count());
+    let rows = record.guesses.len();
+    let grid_width = word_len * (TILE + GAP) - GAP;
+    let grid_height = (rows * (TILE + GAP)).saturating_sub(GAP);
+    let width = WIDTH.max(grid_width + 2 * MARGIN);
+    let height = HEADER + grid_height + MARGIN;
+
+    let won = record
+        .guesses
+        .last()
+        .is_some_and(|g| g.matches.iter().all(|&m| m == Match::Correct));
+    let count = if won {
+        rows.to_string()
+    } else {
+        "X".to_owned()
+    };
+    let number = record.number.map(|n| format!("#{n} ")).unwrap_or_default();
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{width}" height="{height}">"#
+    );
+    // writing to a String can't fail
+    let _ = write!(
+        svg,
+        r##"<rect width="{width}" height="{height}" fill="{BACKGROUND}"/><text x="{}" y="{}" font-family="sans-serif" font-size="24" font-weight="bold" fill="#ffffff" text-anchor="middle" dominant-baseline="central">yawc {number}{count}/{MAX_GUESSES}</text>"##,
+        width / 2,
+        HEADER / 2,
+    );
+    let left = (width - grid_width) / 2;
+    for (row, guess) in record.guesses.iter().enumerate() {
+        let y = HEADER + row * (TILE + GAP);
+        for (col, (c, &m)) in guess.word.chars().zip(&guess.matches).enumerate() {
+            let x = left + col * (TILE + GAP);
+            let (fill, text) = match m {
+                Match::Correct => (hex(theme.correct, "#6aaa64"), hex(theme.text, "#ffffff")),
+                Match::Misplaced => (hex(theme.misplaced, "#c9b458"), hex(theme.text, "#ffffff")),
+                Match::Incorrect => (INCORRECT_TILE.to_owned(), hex(theme.incorrect, "#ffffff")),
+            };
+            let _ = write!(
+                svg,
+                r##"<rect x="{x}" y="{y}" width="{TILE}" height="{TILE}" fill="{fill}"/><text x="{}" y="{}" font-family="sans-serif" font-size="32" font-weight="bold" fill="{text}" text-anchor="middle" dominant-baseline="central">{}</text>"##,
+                x + TILE / 2,
+                y + TILE / 2,
+                escape(&c.to_uppercase().to_string()),
+            );
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// `color` as `#rrggbb`, or `default` if it has no value of its own.
+fn hex(color: Color, default: &str) -> String {
+    color_to_rgb(color).map_or_else(
+        || default.to_owned(),
+        |(r, g, b)| format!("#{r:02x}{g:02x}{b:02x}"),
+    )
+}
+
+/// `text` with the characters that mean something in XML escaped.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{GuessEntry, Match::*},
+        theme::CLASSIC,
+    };
+
+    /// Panics unless every tag in `svg` is terminated and closed in the right
+    /// order, every attribute value is quoted and every `&` starts an entity.
+    fn assert_well_formed(svg: &str) {
+        let mut open = Vec::new();
+        for (i, part) in svg.split('<').enumerate() {
+            let text = if i == 0 {
+                part
+            } else {
+                let end = part.find('>').expect("unterminated tag");
+                let tag = &part[..end];
+                assert_eq!(
+                    tag.matches('"').count() % 2,
+                    0,
+                    "unbalanced quotes in <{tag}>"
+                );
+                let name = tag
+                    .trim_start_matches('/')
+                    .split_whitespace()
+                    .next()
+                    .unwrap();
+                if let Some(closing) = tag.strip_prefix('/') {
+                    assert_eq!(open.pop(), Some(closing), "unexpected </{closing}>");
+                } else if !tag.ends_with('/') {
+                    open.push(name);
+                }
+                &part[end + 1..]
+            };
+            assert!(!text.contains('>'), "stray > in {text:?}");
+            for entity in text.split('&').skip(1) {
+                assert!(
+                    ["amp;", "lt;", "gt;"].iter().any(|e| entity.starts_with(e)),
+                    "stray & in {text:?}"
+                );
+            }
+        }
+        assert!(open.is_empty(), "unclosed {open:?}");
+        assert_eq!(svg.trim_end().rsplit('<').next(), Some("/svg>"));
+    }
+
+    fn record(guesses: &[(&str, Vec<Match>)], number: Option<u32>) -> GameRecord {
+        GameRecord {
+            secret_word: "crane".to_owned(),
+            guesses: guesses
+                .iter()
+                .map(|(word, matches)| GuessEntry::new((*word).to_owned(), matches.clone()))
+                .collect(),
+            number,
+        }
+    }
+
+    #[test]
+    fn won_game_is_well_formed() {
+        let svg = generate_wordle_image(
+            &record(
+                &[
+                    (
+                        "slate",
+                        vec![Incorrect, Incorrect, Correct, Incorrect, Misplaced],
+                    ),
+                    ("crane", vec![Correct; 5]),
+                ],
+                Some(1234),
+            ),
+            &CLASSIC,
+        );
+        assert_well_formed(&svg);
+        assert!(svg.contains(">yawc #1234 2/6</text>"));
+    }
+
+    #[test]
+    fn lost_game_is_well_formed() {
+        let guesses: Vec<_> = (0..6).map(|_| ("slate", vec![Incorrect; 5])).collect();
+        let svg = generate_wordle_image(&record(&guesses, None), &CLASSIC);
+        assert_well_formed(&svg);
+        assert!(svg.contains(">yawc X/6</text>"));
+    }
+
+    #[test]
+    fn no_guesses_is_well_formed() {
+        assert_well_formed(&generate_wordle_image(&record(&[], None), &CLASSIC));
+    }
+
+    #[test]
+    fn letters_are_escaped() {
+        let svg = generate_wordle_image(&record(&[("<&>ab", vec![Incorrect; 5])], None), &CLASSIC);
+        assert_well_formed(&svg);
+        assert!(svg.contains(">&lt;</text>") && svg.contains(">&amp;</text>"));
+    }
+}
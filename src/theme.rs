@@ -0,0 +1,440 @@
+use std::{fmt, str::FromStr};
+
+use clap::ValueEnum;
+use crossterm::style::{Color, StyledContent, Stylize};
+
+use crate::game::Match;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    pub name: &'static str,
+    /// Background of correct letters.
+    pub correct: Color,
+    /// Background of misplaced letters.
+    pub misplaced: Color,
+    /// Foreground of incorrect letters.
+    pub incorrect: Color,
+    /// Foreground of correct and misplaced letters.
+    pub text: Color,
+    /// Backgrounds of correct and misplaced letters on terminals with only
+    /// the 16 named colours, for themes whose own would both turn into the
+    /// same one there.
+    pub basic: Option<(Color, Color)>,
+}
+
+pub const CLASSIC: Theme = Theme {
+    name: "classic",
+    correct: Color::Green,
+    misplaced: Color::Yellow,
+    incorrect: Color::White,
+    text: Color::Black,
+    basic: None,
+};
+
+pub const DARK: Theme = Theme {
+    name: "dark",
+    correct: Color::DarkGreen,
+    misplaced: Color::DarkYellow,
+    incorrect: Color::Grey,
+    text: Color::White,
+    basic: None,
+};
+
+pub const HIGH_CONTRAST: Theme = Theme {
+    name: "high-contrast",
+    correct: Color::DarkYellow,
+    misplaced: Color::Blue,
+    incorrect: Color::White,
+    text: Color::Black,
+    basic: None,
+};
+
+pub const OCEAN: Theme = Theme {
+    name: "ocean",
+    correct: Color::Cyan,
+    misplaced: Color::Magenta,
+    incorrect: Color::White,
+    text: Color::Black,
+    basic: None,
+};
+
+/// Ethan Schoonover's Solarized, in 24-bit colour.
+pub const SOLARIZED_DARK: Theme = Theme {
+    name: "solarized-dark",
+    correct: Color::Rgb {
+        r: 133,
+        g: 153,
+        b: 0,
+    },
+    misplaced: Color::Rgb {
+        r: 181,
+        g: 137,
+        b: 0,
+    },
+    incorrect: Color::Rgb {
+        r: 88,
+        g: 110,
+        b: 117,
+    },
+    text: Color::Rgb { r: 0, g: 43, b: 54 },
+    basic: Some((Color::DarkGreen, Color::DarkYellow)),
+};
+
+/// Gruvbox, in the 256-colour palette it was made for.
+pub const GRUVBOX_DARK: Theme = Theme {
+    name: "gruvbox-dark",
+    correct: Color::AnsiValue(142),
+    misplaced: Color::AnsiValue(214),
+    incorrect: Color::AnsiValue(245),
+    text: Color::AnsiValue(235),
+    basic: Some((Color::DarkGreen, Color::DarkYellow)),
+};
+
+pub const THEMES: &[Theme] = &[
+    CLASSIC,
+    DARK,
+    HIGH_CONTRAST,
+    OCEAN,
+    SOLARIZED_DARK,
+    GRUVBOX_DARK,
+];
+
+impl Theme {
+    /// Styles a letter of a submitted guess.
+    #[must_use]
+    pub fn tile(&self, c: char, m: Match) -> StyledContent<char> {
+        let c = uppercase_letter(c);
+        match m {
+            Match::Correct => c.with(self.text).on(self.correct),
+            Match::Misplaced => c.with(self.text).on(self.misplaced),
+            Match::Incorrect => c.with(self.incorrect),
+        }
+    }
+
+    /// The theme with its colours turned into the closest ones the terminal
+    /// can show, or into its [`Theme::basic`] ones with only the named ones.
+    #[must_use]
+    pub fn for_color_support(self, support: ColorSupport) -> Self {
+        let (correct, misplaced) = match (support, self.basic) {
+            (ColorSupport::Basic, Some(basic)) => basic,
+            _ => (self.correct, self.misplaced),
+        };
+        Self {
+            correct: support.fit(correct),
+            misplaced: support.fit(misplaced),
+            incorrect: support.fit(self.incorrect),
+            text: support.fit(self.text),
+            ..self
+        }
+    }
+
+    /// Styles a key of the on-screen keyboard.
+    #[must_use]
+    pub fn key(&self, c: char, m: Option<Match>) -> StyledContent<char> {
+        match m {
+            None => uppercase_letter(c).white().bold(),
+            Some(Match::Incorrect) => uppercase_letter(c).dark_grey().crossed_out().dim(),
+            Some(m) => self.tile(c, m),
+        }
+    }
+}
+
+/// Colours a terminal can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// None at all, like on a `dumb` terminal.
+    None,
+    /// The 16 named ones.
+    Basic,
+    /// The xterm 256-colour palette.
+    Ansi256,
+    /// Any 24-bit colour.
+    TrueColor,
+}
+
+/// The 16 named colours, with the RGB values xterm gives them.
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (205, 0, 0)),
+    (Color::DarkGreen, (0, 205, 0)),
+    (Color::DarkYellow, (205, 205, 0)),
+    (Color::DarkBlue, (0, 0, 238)),
+    (Color::DarkMagenta, (205, 0, 205)),
+    (Color::DarkCyan, (0, 205, 205)),
+    (Color::Grey, (229, 229, 229)),
+    (Color::DarkGrey, (127, 127, 127)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (92, 92, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Levels of each component in the 6×6×6 colour cube of the 256-colour
+/// palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorSupport {
+    /// `color`, or the closest one to it that can be shown.
+    ///
+    /// ```
+    /// use crossterm::style::Color;
+    /// use yawc::theme::ColorSupport;
+    ///
+    /// let orange = Color::Rgb { r: 255, g: 165, b: 0 };
+    /// assert_eq!(ColorSupport::TrueColor.fit(orange), orange);
+    /// assert_eq!(ColorSupport::Ansi256.fit(orange), Color::AnsiValue(214));
+    /// assert_eq!(ColorSupport::Basic.fit(orange), Color::DarkYellow);
+    /// assert_eq!(ColorSupport::Basic.fit(Color::AnsiValue(46)), Color::Green);
+    /// assert_eq!(ColorSupport::Basic.fit(Color::Green), Color::Green);
+    /// assert_eq!(ColorSupport::None.fit(Color::Green), Color::Reset);
+    /// ```
+    #[must_use]
+    pub fn fit(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorSupport::None, _) => Color::Reset,
+            (ColorSupport::Ansi256, Color::Rgb { r, g, b }) => {
+                let level = |v: u8| {
+                    (0..CUBE_LEVELS.len())
+                        .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(v))
+                        .unwrap_or(0) as u8
+                };
+                Color::AnsiValue(16 + 36 * level(r) + 6 * level(g) + level(b))
+            }
+            (ColorSupport::Basic, Color::Rgb { r, g, b }) => closest_basic((r, g, b)),
+            (ColorSupport::Basic, Color::AnsiValue(n)) => closest_basic(ansi_to_rgb(n)),
+            _ => color,
+        }
+    }
+}
+
+/// RGB value of colour `n` of the 256-colour palette.
+fn ansi_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => BASIC_COLORS[usize::from(n)].1,
+        16..=231 => {
+            let n = n - 16;
+            (
+                CUBE_LEVELS[usize::from(n / 36)],
+                CUBE_LEVELS[usize::from(n / 6 % 6)],
+                CUBE_LEVELS[usize::from(n % 6)],
+            )
+        }
+        232.. => {
+            let grey = 8 + (n - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+/// RGB value of `color` as xterm shows it, `None` for [`Color::Reset`].
+#[cfg(feature = "svg")]
+pub(crate) fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb { r, g, b } => Some((r, g, b)),
+        Color::AnsiValue(n) => Some(ansi_to_rgb(n)),
+        color => BASIC_COLORS
+            .iter()
+            .find(|(c, _)| *c == color)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+
+/// The named colour closest to `rgb`.
+fn closest_basic((r, g, b): (u8, u8, u8)) -> Color {
+    let distance = |(r2, g2, b2): (u8, u8, u8)| {
+        u32::from(r.abs_diff(r2)).pow(2)
+            + u32::from(g.abs_diff(g2)).pow(2)
+            + u32::from(b.abs_diff(b2)).pow(2)
+    };
+    BASIC_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// What colours the terminal can show, going by `$COLORTERM` and `$TERM`.
+#[must_use]
+pub fn detect_color_support() -> ColorSupport {
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    let term = std::env::var("TERM").unwrap_or_default();
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        ColorSupport::TrueColor
+    } else if term.contains("256color") {
+        ColorSupport::Ansi256
+    } else if term == "dumb" {
+        ColorSupport::None
+    } else {
+        ColorSupport::Basic
+    }
+}
+
+/// Backgrounds of correct letters in `--rainbow` mode, light enough for dark
+/// text and none of them orange, purple or blue like the misplaced ones.
+const RAINBOW_CORRECT: [Color; 8] = [
+    Color::Rgb {
+        r: 106,
+        g: 170,
+        b: 100,
+    },
+    Color::Rgb {
+        r: 72,
+        g: 199,
+        b: 142,
+    },
+    Color::Rgb {
+        r: 64,
+        g: 190,
+        b: 190,
+    },
+    Color::Rgb {
+        r: 150,
+        g: 200,
+        b: 80,
+    },
+    Color::Rgb {
+        r: 200,
+        g: 220,
+        b: 90,
+    },
+    Color::Rgb {
+        r: 230,
+        g: 120,
+        b: 150,
+    },
+    Color::Rgb {
+        r: 250,
+        g: 128,
+        b: 114,
+    },
+    Color::Rgb {
+        r: 190,
+        g: 160,
+        b: 90,
+    },
+];
+
+/// Backgrounds of misplaced letters in `--rainbow` mode.
+const RAINBOW_MISPLACED: [Color; 3] = [
+    Color::Rgb {
+        r: 255,
+        g: 165,
+        b: 0,
+    },
+    Color::Rgb {
+        r: 200,
+        g: 140,
+        b: 230,
+    },
+    Color::Rgb {
+        r: 120,
+        g: 180,
+        b: 250,
+    },
+];
+
+/// Colour of correct letters in the `game_number`th game of `--rainbow`
+/// mode, going round [`RAINBOW_CORRECT`].
+#[must_use]
+pub fn next_correct_color(game_number: usize) -> Color {
+    RAINBOW_CORRECT[game_number % RAINBOW_CORRECT.len()]
+}
+
+/// Colour of misplaced letters in the `game_number`th game of `--rainbow`
+/// mode, going round [`RAINBOW_MISPLACED`].
+#[must_use]
+pub fn next_misplaced_color(game_number: usize) -> Color {
+    RAINBOW_MISPLACED[game_number % RAINBOW_MISPLACED.len()]
+}
+
+/// Uppercase form of a letter, or the letter itself when that's more than one
+/// character, like `ß`.
+#[must_use]
+pub fn uppercase_letter(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        CLASSIC
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        THEMES
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| {
+                let names: Vec<_> = THEMES.iter().map(|t| t.name).collect();
+                format!("unknown theme, expected one of: {}", names.join(", "))
+            })
+    }
+}
+
+/// Set of emoji shown instead of letters with `--emoji`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum EmojiStyle {
+    /// 🟩 🟨 ⬛, as in the Wordle share text
+    #[default]
+    Wordle,
+    /// 🟩 🟨 ⬜, which stands out on Discord's dark background
+    Discord,
+}
+
+impl EmojiStyle {
+    #[must_use]
+    pub fn symbol(self, m: Match) -> &'static str {
+        match (self, m) {
+            (_, Match::Correct) => "🟩",
+            (_, Match::Misplaced) => "🟨",
+            (EmojiStyle::Wordle, Match::Incorrect) => "⬛",
+            (EmojiStyle::Discord, Match::Incorrect) => "⬜",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_and_misplaced_stay_apart() {
+        for theme in THEMES {
+            for support in [
+                ColorSupport::Basic,
+                ColorSupport::Ansi256,
+                ColorSupport::TrueColor,
+            ] {
+                let fitted = theme.for_color_support(support);
+                assert_ne!(fitted.correct, fitted.misplaced, "{theme} in {support:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn basic_fallback_only_without_more_colours() {
+        let basic = SOLARIZED_DARK.for_color_support(ColorSupport::Basic);
+        assert_eq!(
+            (basic.correct, basic.misplaced),
+            (Color::DarkGreen, Color::DarkYellow)
+        );
+        let ansi = GRUVBOX_DARK.for_color_support(ColorSupport::Ansi256);
+        assert_eq!(ansi.correct, Color::AnsiValue(142));
+    }
+}
@@ -0,0 +1,32 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// Tips and fun facts, one of which is shown when the game starts.
+pub const TIPS: &[&str] = &[
+    "Tip: Ctrl+H shows the letters you haven't tried yet.",
+    "Tip: Ctrl+F shows the most likely letters at each position.",
+    "Tip: Shift+G shows how often two positions hold the same letter.",
+    "Tip: type a few letters and press Tab to complete the word.",
+    "Tip: Ctrl+R reveals the word when you're stuck.",
+    "Tip: Ctrl+L repaints the screen if it gets garbled.",
+    "Tip: Ctrl+Q hides the messages under the keyboard, --quiet starts without them.",
+    "Tip: Ctrl+C saves the game, yawc resume picks it up again.",
+    "Tip: --focus or Ctrl+O dims the guesses you've already made.",
+    "Tip: --smart-keyboard marks keys with the places their letter can't be.",
+    "Tip: --first-guess crane plays your favourite opener for you.",
+    "Tip: yawc scores shows how you did in past games.",
+    "Fun fact: the secret words are all common English words.",
+    "Fun fact: using all 26 letters in one game is a pangram.",
+    "Fun fact: a guess can score up to 15 quality points.",
+];
+
+/// One of the [`TIPS`], picked at random.
+///
+/// ```
+/// use yawc::tips::{random_tip, TIPS};
+///
+/// assert!(TIPS.contains(&random_tip(&mut rand::thread_rng())));
+/// ```
+#[must_use]
+pub fn random_tip(rng: &mut impl Rng) -> &'static str {
+    TIPS.choose(rng).copied().unwrap_or_default()
+}
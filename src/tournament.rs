@@ -0,0 +1,150 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::{
+    game::{GuessHistory, Match},
+    solver::solve,
+    stats::MAX_GUESSES,
+    words::WORDS,
+};
+
+/// Number of games in a tournament.
+pub const ROUNDS: usize = 10;
+
+/// Number of players in a [`TournamentBracket`].
+pub const PLAYERS: usize = 4;
+
+/// Score of a lost game, one more than the most a won one can have.
+const LOSS_SCORE: usize = MAX_GUESSES + 1;
+
+pub struct SingleRoundResult {
+    pub word: &'static str,
+    /// Guesses used to find the word, [`None`] if it wasn't found.
+    pub guesses: Option<usize>,
+}
+
+impl SingleRoundResult {
+    #[must_use]
+    pub fn score(&self) -> usize {
+        self.guesses.unwrap_or(LOSS_SCORE)
+    }
+}
+
+/// A fixed sequence of [`ROUNDS`] games drawn from `seed`, scored by the total
+/// number of guesses used, so lower is better.
+pub struct TournamentGame {
+    pub rounds: Vec<SingleRoundResult>,
+    pub seed: u64,
+    words: Vec<&'static str>,
+}
+
+impl TournamentGame {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self {
+            rounds: Vec::with_capacity(ROUNDS),
+            seed,
+            words: WORDS.choose_multiple(&mut rng, ROUNDS).copied().collect(),
+        }
+    }
+
+    /// Secret word of the current round.
+    #[must_use]
+    pub fn current_word(&self) -> Option<&'static str> {
+        self.words.get(self.rounds.len()).copied()
+    }
+
+    /// Finishes the current round.
+    pub fn record(&mut self, guesses: Option<usize>) {
+        if let Some(word) = self.current_word() {
+            self.rounds.push(SingleRoundResult { word, guesses });
+        }
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.rounds.len() == ROUNDS
+    }
+
+    #[must_use]
+    pub fn score(&self) -> usize {
+        self.rounds.iter().map(SingleRoundResult::score).sum()
+    }
+
+    /// Score the solver gets on the same words.
+    #[must_use]
+    pub fn solver_score(&self) -> usize {
+        self.words
+            .iter()
+            .map(|w| solve(w).unwrap_or(LOSS_SCORE))
+            .sum()
+    }
+}
+
+/// One player's game in a [`TournamentBracket`].
+pub struct PlayerSession {
+    pub name: String,
+    pub history: GuessHistory,
+}
+
+impl PlayerSession {
+    /// Guesses used to find the word, [`None`] if it wasn't found.
+    #[must_use]
+    pub fn guesses(&self) -> Option<usize> {
+        let found = self
+            .history
+            .last()
+            .is_some_and(|g| g.matches.iter().all(|m| *m == Match::Correct));
+        found.then_some(self.history.len())
+    }
+
+    #[must_use]
+    pub fn score(&self) -> usize {
+        self.guesses().unwrap_or(LOSS_SCORE)
+    }
+}
+
+/// [`PLAYERS`] players taking turns at guessing the same word on one
+/// terminal, ranked by the number of guesses they used.
+pub struct TournamentBracket {
+    names: Vec<String>,
+    pub players: Vec<PlayerSession>,
+}
+
+impl TournamentBracket {
+    #[must_use]
+    pub fn new(names: Vec<String>) -> Self {
+        Self {
+            players: Vec::with_capacity(names.len()),
+            names,
+        }
+    }
+
+    /// Name of the player whose turn it is.
+    #[must_use]
+    pub fn current_player(&self) -> Option<&str> {
+        self.names.get(self.players.len()).map(String::as_str)
+    }
+
+    /// Finishes the current player's turn.
+    pub fn record(&mut self, history: GuessHistory) {
+        if let Some(name) = self.current_player() {
+            let name = name.to_owned();
+            self.players.push(PlayerSession { name, history });
+        }
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.players.len() == self.names.len()
+    }
+
+    /// Players who finished their turn, best first. Ties keep the order the
+    /// players played in.
+    #[must_use]
+    pub fn ranking(&self) -> Vec<&PlayerSession> {
+        let mut ranking: Vec<_> = self.players.iter().collect();
+        ranking.sort_by_key(|p| p.score());
+        ranking
+    }
+}
@@ -0,0 +1,198 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use crossterm::{
+    cursor,
+    event::{self, KeyCode, KeyModifiers},
+    execute,
+    style::{self, Stylize},
+    terminal,
+};
+
+use crate::{
+    config::Config,
+    mode::Mode,
+    raw::{Terminal, TerminalInfo},
+};
+
+/// Way of playing picked from the menu yawc starts with, or with
+/// `--variant`. The ones yawc can't play yet are in the menu but can't be
+/// picked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum WordleVariant {
+    /// 5-letter words in 6 guesses
+    Classic,
+    /// Like classic, but letters revealed must be used again
+    Hard,
+    #[value(skip)]
+    Absurdle,
+    /// 7-letter words in 6 guesses
+    Plus,
+    #[value(skip)]
+    Double,
+    #[value(skip)]
+    Quordle,
+}
+
+impl WordleVariant {
+    /// In the order of the menu.
+    pub const ALL: [Self; 6] = [
+        Self::Classic,
+        Self::Hard,
+        Self::Absurdle,
+        Self::Plus,
+        Self::Double,
+        Self::Quordle,
+    ];
+
+    /// Name shown in the menu.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::Hard => "Hard",
+            Self::Absurdle => "Absurdle",
+            Self::Plus => "Wordle Plus",
+            Self::Double => "Double",
+            Self::Quordle => "Quordle",
+        }
+    }
+
+    /// Whether yawc can play it.
+    #[must_use]
+    pub fn is_available(self) -> bool {
+        matches!(self, Self::Classic | Self::Hard | Self::Plus)
+    }
+
+    /// The variant `config` plays, going by `--mode` and `--hard`.
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        match (config.mode, config.hard) {
+            (Mode::Plus, _) => Self::Plus,
+            (Mode::Classic, true) => Self::Hard,
+            (Mode::Classic, false) => Self::Classic,
+        }
+    }
+
+    /// Changes `config` to play the variant, leaving everything else as it
+    /// is. Does nothing for the ones that aren't available.
+    pub fn apply(self, config: &mut Config) {
+        match self {
+            Self::Classic => {
+                config.mode = Mode::Classic;
+                config.hard = false;
+            }
+            Self::Hard => {
+                config.mode = Mode::Classic;
+                config.hard = true;
+            }
+            Self::Plus => {
+                config.mode = Mode::Plus;
+                config.hard = false;
+            }
+            Self::Absurdle | Self::Double | Self::Quordle => {}
+        }
+    }
+}
+
+/// The default [`Config`] for playing `variant`.
+///
+/// ```
+/// use yawc::{
+///     mode::Mode,
+///     variant::{configure_for_variant, WordleVariant},
+/// };
+///
+/// let config = configure_for_variant(WordleVariant::Hard);
+/// assert_eq!(config.mode, Mode::Classic);
+/// assert!(config.hard);
+/// assert_eq!(WordleVariant::from_config(&config), WordleVariant::Hard);
+///
+/// let config = configure_for_variant(WordleVariant::Plus);
+/// assert_eq!(config.mode, Mode::Plus);
+/// assert_eq!(WordleVariant::from_config(&config), WordleVariant::Plus);
+/// ```
+#[must_use]
+pub fn configure_for_variant(variant: WordleVariant) -> Config {
+    let mut config = Config::default();
+    variant.apply(&mut config);
+    config
+}
+
+/// Shows the menu of variants until one is picked with `Enter` or its
+/// number, starting at `selected`. `None` if the player quits instead.
+#[allow(clippy::missing_errors_doc)]
+pub fn select_variant(selected: WordleVariant) -> io::Result<Option<WordleVariant>> {
+    let mut term = Terminal::new()?;
+    let mut selected = WordleVariant::ALL
+        .iter()
+        .position(|&v| v == selected)
+        .unwrap_or(0);
+    loop {
+        draw_menu(&mut term, selected)?;
+        let event::Event::Key(key) = term.read_event()? else {
+            continue;
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(None),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(None),
+            KeyCode::Up => selected = next_available(selected, -1),
+            KeyCode::Down => selected = next_available(selected, 1),
+            KeyCode::Enter => return Ok(Some(WordleVariant::ALL[selected])),
+            KeyCode::Char(c) => {
+                let picked = c
+                    .to_digit(10)
+                    .and_then(|n| WordleVariant::ALL.get((n as usize).checked_sub(1)?));
+                if let Some(&variant) = picked.filter(|v| v.is_available()) {
+                    return Ok(Some(variant));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The closest available variant to the `selected`th going up or down the
+/// menu, or the `selected`th itself if there's none.
+fn next_available(selected: usize, step: isize) -> usize {
+    let mut i = selected;
+    while let Some(next) = i.checked_add_signed(step) {
+        match WordleVariant::ALL.get(next) {
+            Some(v) if v.is_available() => return next,
+            Some(_) => i = next,
+            None => break,
+        }
+    }
+    selected
+}
+
+fn draw_menu(term: &mut Terminal, selected: usize) -> io::Result<()> {
+    let (width, height) = term.size()?;
+    execute!(term, terminal::Clear(terminal::ClearType::All))?;
+    let top = (height / 2).saturating_sub(5);
+    let x = (width / 2).saturating_sub(14);
+    execute!(
+        term,
+        cursor::MoveTo(x, top),
+        style::PrintStyledContent("Which Wordle do you want to play?".bold())
+    )?;
+    for ((i, variant), y) in WordleVariant::ALL.iter().enumerate().zip(top + 2..) {
+        let marker = if i == selected { '▶' } else { ' ' };
+        let line = format!("{marker} {}. {}", i + 1, variant.name());
+        execute!(term, cursor::MoveTo(x, y))?;
+        if variant.is_available() {
+            execute!(term, style::Print(line))?;
+        } else {
+            execute!(
+                term,
+                style::PrintStyledContent(format!("{line} (coming soon)").dark_grey())
+            )?;
+        }
+    }
+    execute!(
+        term,
+        cursor::MoveTo(x, top + 9),
+        style::PrintStyledContent("↑/↓ to choose, Enter to play, Esc to quit".dark_grey())
+    )?;
+    term.flush()
+}
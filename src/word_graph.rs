@@ -0,0 +1,87 @@
+use std::fmt;
+
+use crossterm::{
+    cursor,
+    style::{self, Color, Stylize},
+};
+
+use crate::analysis::position_correlation_matrix;
+
+/// Box showing how often the remaining candidates have the same letter at
+/// two positions, drawn with its top left corner at the cursor. Cells go
+/// from white, for pairs of positions that never share a letter, to blue,
+/// for ones that always do.
+pub struct WordGraph {
+    matrix: [[f32; 5]; 5],
+}
+
+impl WordGraph {
+    #[must_use]
+    pub fn new(candidates: &[&str]) -> Self {
+        Self {
+            matrix: position_correlation_matrix(candidates),
+        }
+    }
+
+    /// Size of the box in columns and rows.
+    pub const WIDTH: u16 = 40;
+    pub const HEIGHT: u16 = 9;
+}
+
+/// Colour of a cell for `fraction` of the candidates.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn gradient(fraction: f32) -> Color {
+    let fade = (255.0 * (1.0 - fraction.clamp(0.0, 1.0))).round() as u8;
+    Color::Rgb {
+        r: fade,
+        g: fade,
+        b: 255,
+    }
+}
+
+impl fmt::Display for WordGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = |left, middle, right| {
+            let mut line = format!("{left}───{middle}");
+            for i in 0..5 {
+                line.push_str("──────");
+                line.push(if i == 4 { right } else { middle });
+            }
+            line
+        };
+        let header: String = (1..=5).map(|i| format!("  {i}   │")).collect();
+        for line in [
+            line('┌', '┬', '┐'),
+            format!("│   │{header}"),
+            line('├', '┼', '┤'),
+        ] {
+            write!(
+                f,
+                "{}{line}{}{}",
+                cursor::SavePosition,
+                cursor::RestorePosition,
+                cursor::MoveDown(1)
+            )?;
+        }
+        for (i, row) in self.matrix.iter().enumerate() {
+            write!(f, "{}│ {} │", cursor::SavePosition, i + 1)?;
+            for &fraction in row {
+                let cell = format!(" {:>3.0}% ", fraction * 100.0)
+                    .with(if fraction > 0.5 {
+                        Color::White
+                    } else {
+                        Color::Black
+                    })
+                    .on(gradient(fraction));
+                write!(f, "{cell}│")?;
+            }
+            write!(f, "{}{}", cursor::RestorePosition, cursor::MoveDown(1))?;
+        }
+        write!(
+            f,
+            "{}{}",
+            cursor::SavePosition,
+            style::Print(line('└', '┴', '┘'))
+        )
+    }
+}
@@ -1298,3 +1298,104 @@ pub const ACCEPTABLE: &[&str] = &[
     "zoppa", "zoppo", "zoril", "zoris", "zorro", "zouks", "zowee", "zowie", "zulus", "zupan",
     "zupas", "zuppa", "zurfs", "zuzim", "zygal", "zygon", "zymes", "zymic",
 ];
+
+/// Index of `word` in [`WORDS`], so that it can be referred to by number.
+/// The list isn't sorted, as the word of the day is picked by its order, so
+/// this goes through it from the start.
+///
+/// ```
+/// use yawc::words::{word_position, WORDS};
+///
+/// let i = word_position("crane").unwrap();
+/// assert_eq!(WORDS[i], "crane");
+/// assert_eq!(word_position("xxxxx"), None);
+/// ```
+#[must_use]
+pub fn word_position(word: &str) -> Option<usize> {
+    WORDS.iter().position(|&w| w == word)
+}
+
+/// Secret words of the Plus mode, which are also the only ones accepted as
+/// guesses in it.
+pub const WORDS_PLUS: &[&str] = &[
+    "ability", "absence", "academy", "account", "accused", "achieve", "acquire", "address",
+    "advance", "adverse", "advised", "adviser", "against", "airline", "airport", "alcohol",
+    "alleged", "already", "analyst", "ancient", "another", "anxiety", "anxious", "anybody",
+    "applied", "arrange", "arrival", "article", "assault", "attempt", "attract", "auction",
+    "average", "backing", "balance", "banking", "barrier", "battery", "bearing", "beating",
+    "because", "bedroom", "believe", "beneath", "benefit", "besides", "between", "billion",
+    "binding", "brother", "brought", "burning", "cabinet", "caliber", "calling", "capable",
+    "capital", "captain", "caption", "capture", "careful", "carrier", "caution", "ceiling",
+    "central", "century", "certain", "chamber", "channel", "chapter", "charity", "charter",
+    "checked", "chicken", "chronic", "circuit", "classic", "climate", "closing", "closure",
+    "clothes", "collect", "college", "combine", "comfort", "command", "comment", "compact",
+    "company", "compare", "compete", "complex", "concept", "concern", "concert", "conduct",
+    "confirm", "connect", "consent", "consist", "contact", "contain", "content", "contest",
+    "context", "control", "convert", "correct", "council", "counsel", "counter", "country",
+    "crucial", "crystal", "culture", "current", "cutting", "dealing", "decided", "decline",
+    "default", "defence", "deficit", "deliver", "density", "deposit", "desktop", "despite",
+    "destroy", "develop", "devoted", "diamond", "digital", "discuss", "disease", "display",
+    "dispute", "distant", "diverse", "divided", "drawing", "driving", "dynamic", "eastern",
+    "economy", "edition", "elderly", "element", "engaged", "enhance", "essence", "evening",
+    "evident", "exactly", "examine", "example", "excited", "exclude", "exhibit", "expense",
+    "explain", "explore", "express", "extreme", "factory", "faculty", "failing", "failure",
+    "fashion", "feature", "federal", "feeling", "fiction", "fifteen", "filling", "finance",
+    "finding", "fishing", "fitness", "foreign", "forever", "formula", "fortune", "forward",
+    "freedom", "further", "gallery", "general", "genetic", "genuine", "gesture", "getting",
+    "glimpse", "grocery", "habitat", "handful", "harmony", "healthy", "hearing", "heavily",
+    "helpful", "herself", "highway", "himself", "history", "holding", "holiday", "housing",
+    "however", "hundred", "hunting", "husband", "illegal", "illness", "imagine", "impress",
+    "improve", "include", "initial", "inquiry", "insight", "install", "instant", "instead",
+    "intense", "interim", "involve", "jointly", "journal", "journey", "justice", "justify",
+    "keeping", "killing", "kingdom", "kitchen", "knowing", "landing", "largely", "lasting",
+    "leading", "learned", "leisure", "liberal", "liberty", "library", "licence", "limited",
+    "listing", "logical", "loyalty", "machine", "manager", "married", "massive", "maximum",
+    "meaning", "measure", "medical", "meeting", "mention", "message", "million", "mineral",
+    "minimal", "minimum", "missing", "mission", "mistake", "mixture", "monitor", "monthly",
+    "morning", "musical", "mystery", "natural", "neither", "nervous", "network", "neutral",
+    "notable", "nothing", "nowhere", "nuclear", "numeral", "nursing", "obvious", "offense",
+    "officer", "ongoing", "opening", "operate", "opinion", "optical", "organic", "outcome",
+    "outdoor", "outlook", "outside", "overall", "package", "painful", "painter", "parking",
+    "partial", "partner", "passage", "passion", "patient", "pattern", "payment", "penalty",
+    "pending", "pension", "percent", "perfect", "perform", "perhaps", "picture", "pioneer",
+    "plastic", "pointed", "popular", "portion", "poverty", "precise", "predict", "premier",
+    "premium", "prepare", "present", "prevent", "primary", "printer", "privacy", "private",
+    "problem", "proceed", "process", "produce", "product", "profile", "program", "project",
+    "promise", "promote", "propose", "protect", "protein", "protest", "provide", "publish",
+    "purpose", "qualify", "quality", "quarter", "radical", "railway", "readily", "reading",
+    "reality", "realize", "receipt", "receive", "recover", "reflect", "regular", "related",
+    "release", "remains", "removal", "replace", "request", "require", "reserve", "resolve",
+    "respect", "respond", "restore", "retired", "revenue", "reverse", "rolling", "romance",
+    "roughly", "routine", "running", "satisfy", "science", "section", "segment", "serious",
+    "service", "session", "setting", "seventh", "several", "shortly", "showing", "silence",
+    "silicon", "similar", "sitting", "sixteen", "skilled", "smoking", "society", "somehow",
+    "someone", "speaker", "special", "species", "sponsor", "station", "storage", "strange",
+    "stretch", "student", "subject", "succeed", "success", "suggest", "summary", "support",
+    "suppose", "supreme", "surface", "surgery", "surplus", "survive", "suspect", "sustain",
+    "teacher", "telling", "tension", "theatre", "therapy", "thereby", "thought", "through",
+    "tonight", "totally", "touched", "towards", "traffic", "trouble", "turning", "typical",
+    "uniform", "unknown", "unusual", "upgrade", "useless", "utility", "variety", "various",
+    "vehicle", "venture", "version", "veteran", "victory", "viewing", "village", "violent",
+    "virtual", "visible", "waiting", "walking", "wanting", "warning", "warrant", "wealthy",
+    "weather", "website", "wedding", "weekend", "welcome", "welfare", "western", "whereas",
+    "whether", "willing", "winning", "without", "witness", "working", "writing", "written",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_word_has_a_position() {
+        let i = word_position("crane").unwrap();
+        assert_eq!(WORDS[i], "crane");
+        assert_eq!(word_position(WORDS[0]), Some(0));
+    }
+
+    #[test]
+    fn unknown_word_has_none() {
+        assert_eq!(word_position("xxxxx"), None);
+        assert_eq!(word_position(""), None);
+        assert_eq!(word_position("ability"), None);
+    }
+}
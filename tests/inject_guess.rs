@@ -0,0 +1,63 @@
+//! A whole game played with [`Game::inject_guess`], without a terminal.
+
+use yawc::{
+    config::Config,
+    game::{check_word, Game, GameError, GameState, Match},
+};
+
+#[test]
+fn six_guess_game() -> Result<(), GameError> {
+    let mut game = Game::headless(Config::default(), "crane")?;
+    let guesses = ["slate", "pious", "dwelt", "boxer", "grace", "crane"];
+    for (i, guess) in guesses.iter().enumerate() {
+        let state = game.inject_guess(guess)?;
+        let record = game.record();
+        assert_eq!(record.guesses.len(), i + 1);
+        assert_eq!(record.guesses[i].word, *guess);
+        assert_eq!(record.guesses[i].matches, check_word("crane", guess));
+        assert!(game.possible_words().contains(&"crane"));
+        let expected = (i == guesses.len() - 1).then_some(GameState::Win);
+        assert_eq!(state, expected, "after {guess}");
+    }
+    assert_eq!(game.keyboard().letter_state('c'), Some(Match::Correct));
+    assert_eq!(game.keyboard().letter_state('s'), Some(Match::Incorrect));
+    assert_eq!(game.keyboard().letter_state('z'), None);
+    Ok(())
+}
+
+#[test]
+fn six_wrong_guesses_lose() -> Result<(), GameError> {
+    let mut game = Game::headless(Config::default(), "crane")?;
+    for guess in ["slate", "pious", "dwelt", "boxer", "grace"] {
+        assert_eq!(game.inject_guess(guess)?, None);
+    }
+    assert_eq!(game.inject_guess("frank")?, Some(GameState::Loose));
+    Ok(())
+}
+
+#[test]
+fn invalid_guesses_are_errors() -> Result<(), GameError> {
+    let mut game = Game::headless(Config::default(), "crane")?;
+    assert!(matches!(
+        game.inject_guess("xxxxx"),
+        Err(GameError::InvalidWord(_))
+    ));
+    assert!(matches!(
+        game.inject_guess("cranes"),
+        Err(GameError::InvalidWord(_))
+    ));
+    assert!(game.record().guesses.is_empty());
+    Ok(())
+}
+
+#[test]
+fn no_guess_after_the_game_is_over() -> Result<(), GameError> {
+    let mut game = Game::headless(Config::default(), "crane")?;
+    assert_eq!(game.inject_guess("crane")?, Some(GameState::Win));
+    assert!(matches!(
+        game.inject_guess("slate"),
+        Err(GameError::GameOver)
+    ));
+    assert_eq!(game.record().guesses.len(), 1);
+    Ok(())
+}
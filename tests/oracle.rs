@@ -0,0 +1,49 @@
+//! Checks [`check_word`] against guesses at past answers of the New York
+//! Times Wordle, in `tests/nyt_answers.tsv`, so that changing it can't quietly
+//! change how words are matched.
+
+use yawc::game::{check_word, Match};
+
+/// A line of `nyt_answers.tsv`: the number of the Wordle, its answer, a
+/// guess and the matches the guess got.
+struct Answer<'a> {
+    number: &'a str,
+    secret: &'a str,
+    guess: &'a str,
+    expected: Vec<Match>,
+}
+
+fn answers() -> Vec<Answer<'static>> {
+    include_str!("nyt_answers.tsv")
+        .lines()
+        .filter(|l| !l.starts_with('#') && !l.trim().is_empty())
+        .map(|line| {
+            let [number, secret, guess, expected] = line.split('\t').collect::<Vec<_>>()[..] else {
+                panic!("malformed line {line:?}");
+            };
+            let expected = expected
+                .chars()
+                .map(|c| c.to_string().parse())
+                .collect::<Result<_, _>>()
+                .unwrap_or_else(|e| panic!("malformed matches in {line:?}: {e}"));
+            Answer {
+                number,
+                secret,
+                guess,
+                expected,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn check_word_matches_nyt_wordle() {
+    let answers = answers();
+    assert!(!answers.is_empty());
+    let wrong: Vec<_> = answers
+        .iter()
+        .filter(|a| check_word(a.secret, a.guess)[..] != a.expected[..])
+        .map(|a| format!("Wordle {} ({}), {}", a.number, a.secret, a.guess))
+        .collect();
+    assert!(wrong.is_empty(), "wrong matches for {wrong:#?}");
+}
@@ -0,0 +1,78 @@
+//! Whole games played with [`Game::play_guesses`], without a terminal.
+
+use yawc::{
+    config::Config,
+    game::{Game, GameError, GameResult, GameState},
+};
+
+fn result(state: Option<GameState>, guesses_used: usize) -> GameResult {
+    GameResult {
+        state,
+        guesses_used,
+    }
+}
+
+#[test]
+fn win_in_one_guess() -> Result<(), GameError> {
+    assert_eq!(
+        Game::headless(Config::default(), "crane")?.play_guesses(&["crane"])?,
+        result(Some(GameState::Win), 1)
+    );
+    Ok(())
+}
+
+#[test]
+fn win_in_three_guesses() -> Result<(), GameError> {
+    assert_eq!(
+        Game::headless(Config::default(), "crane")?.play_guesses(&["slate", "pious", "crane"])?,
+        result(Some(GameState::Win), 3)
+    );
+    Ok(())
+}
+
+#[test]
+fn win_in_six_guesses() -> Result<(), GameError> {
+    assert_eq!(
+        Game::headless(Config::default(), "crane")?
+            .play_guesses(&["slate", "pious", "dwelt", "boxer", "grace", "crane"])?,
+        result(Some(GameState::Win), 6)
+    );
+    Ok(())
+}
+
+#[test]
+fn lose_with_six_wrong_guesses() -> Result<(), GameError> {
+    assert_eq!(
+        Game::headless(Config::default(), "crane")?
+            .play_guesses(&["slate", "pious", "dwelt", "boxer", "grace", "frank"])?,
+        result(Some(GameState::Loose), 6)
+    );
+    Ok(())
+}
+
+#[test]
+fn unfinished_game() -> Result<(), GameError> {
+    assert_eq!(
+        Game::headless(Config::default(), "crane")?.play_guesses(&["slate"])?,
+        result(None, 1)
+    );
+    Ok(())
+}
+
+#[test]
+fn no_guess_after_the_game_is_over() -> Result<(), GameError> {
+    assert!(matches!(
+        Game::headless(Config::default(), "crane")?.play_guesses(&["crane", "slate"]),
+        Err(GameError::GameOver)
+    ));
+    Ok(())
+}
+
+#[test]
+fn invalid_word_is_not_played() -> Result<(), GameError> {
+    assert!(matches!(
+        Game::headless(Config::default(), "crane")?.play_guesses(&["slate", "xxxxx"]),
+        Err(GameError::InvalidWord(word)) if word == "xxxxx"
+    ));
+    Ok(())
+}